@@ -0,0 +1,126 @@
+mod porter;
+
+use once_cell::sync::Lazy;
+use std::collections::HashSet;
+
+/// Shared text-analysis pipeline: lowercase → split on non-alphanumeric
+/// boundaries → stopword removal → optional Porter stemming. Both
+/// `BM25Index` and `SkillExtractor` run text through the same `Analyzer` so
+/// an indexed document and an incoming query (or resume text) line up on
+/// the same terms.
+#[derive(Clone)]
+pub struct Analyzer {
+    stopwords: HashSet<String>,
+    stemming: bool,
+}
+
+static DEFAULT_STOPWORDS: Lazy<HashSet<String>> = Lazy::new(|| {
+    [
+        "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "if", "in", "into", "is",
+        "it", "no", "not", "of", "on", "or", "such", "that", "the", "their", "then", "there",
+        "these", "they", "this", "to", "was", "will", "with", "i", "you", "he", "she", "we",
+        "do", "does", "did", "have", "has", "had", "can", "could", "should", "would", "may",
+        "might", "must", "shall", "am", "been", "being", "from", "up", "down", "out", "about",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect()
+});
+
+impl Default for Analyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Analyzer {
+    /// Default pipeline: stopwords removed, stemming off. Stemming defaults
+    /// off so existing exact-match behavior (BM25 postings, skill set
+    /// lookups) doesn't change until a caller opts in.
+    pub fn new() -> Self {
+        Self {
+            stopwords: DEFAULT_STOPWORDS.clone(),
+            stemming: false,
+        }
+    }
+
+    /// Default stopword list, with stemming toggled explicitly.
+    pub fn with_stemming(stemming: bool) -> Self {
+        Self {
+            stopwords: DEFAULT_STOPWORDS.clone(),
+            stemming,
+        }
+    }
+
+    /// Fully custom stopword list and stemming toggle.
+    pub fn with_params(stopwords: HashSet<String>, stemming: bool) -> Self {
+        Self { stopwords, stemming }
+    }
+
+    /// Tokenize `text` and run it through stopword removal and (if enabled) stemming.
+    pub fn analyze(&self, text: &str) -> Vec<String> {
+        self.analyze_tokens(tokenize_raw(text))
+    }
+
+    /// Run stopword removal and stemming over already-split raw tokens,
+    /// e.g. a single query term that shouldn't be re-tokenized.
+    pub fn analyze_tokens(&self, tokens: Vec<String>) -> Vec<String> {
+        tokens
+            .into_iter()
+            .filter(|t| !self.stopwords.contains(t))
+            .map(|t| if self.stemming { porter::stem(&t) } else { t })
+            .collect()
+    }
+}
+
+/// Lowercase and split into alphanumeric runs of length > 1 — the raw
+/// tokenization stage shared by `Analyzer::analyze` and callers (like the
+/// BM25 query parser) that need to tokenize before applying the rest of the
+/// pipeline themselves.
+pub fn tokenize_raw(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty() && s.len() > 1)
+        .map(|s| s.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_analyze_drops_stopwords() {
+        let analyzer = Analyzer::new();
+        let tokens = analyzer.analyze("the quick brown fox and the lazy dog");
+        assert!(!tokens.contains(&"the".to_string()));
+        assert!(!tokens.contains(&"and".to_string()));
+        assert!(tokens.contains(&"quick".to_string()));
+    }
+
+    #[test]
+    fn test_stemming_off_by_default() {
+        let analyzer = Analyzer::new();
+        let tokens = analyzer.analyze("running runs");
+        assert_eq!(tokens, vec!["running", "runs"]);
+    }
+
+    #[test]
+    fn test_stemming_collapses_related_forms() {
+        let analyzer = Analyzer::with_stemming(true);
+        let tokens = analyzer.analyze("running runs runner");
+        assert_eq!(tokens[0], tokens[1]);
+    }
+
+    #[test]
+    fn test_with_params_uses_custom_stopwords() {
+        let mut stopwords = HashSet::new();
+        stopwords.insert("custom".to_string());
+        let analyzer = Analyzer::with_params(stopwords, false);
+
+        let tokens = analyzer.analyze("custom the word");
+        assert!(!tokens.contains(&"custom".to_string()));
+        // "the" is not in this custom stopword list, so it survives.
+        assert!(tokens.contains(&"the".to_string()));
+    }
+}
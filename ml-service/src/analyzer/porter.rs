@@ -0,0 +1,325 @@
+/// Porter stemmer (Porter, 1980): reduces an English word to its stem by
+/// stripping suffixes in five ordered step groups, gated by the "measure" m
+/// of the stem — the number of vowel-consonant (VC) sequences following an
+/// optional leading consonant run, i.e. a word matches `[C](VC)^m[V]`.
+pub fn stem(word: &str) -> String {
+    let chars: Vec<char> = word.chars().collect();
+    if chars.len() <= 2 {
+        // Too short for any rule to apply meaningfully.
+        return word.to_string();
+    }
+
+    let chars = step1a(chars);
+    let chars = step1b(chars);
+    let chars = step1c(chars);
+    let chars = step2(chars);
+    let chars = step3(chars);
+    let chars = step4(chars);
+    let chars = step5a(chars);
+    let chars = step5b(chars);
+
+    chars.into_iter().collect()
+}
+
+fn is_consonant(chars: &[char], i: usize) -> bool {
+    match chars[i] {
+        'a' | 'e' | 'i' | 'o' | 'u' => false,
+        'y' => {
+            if i == 0 {
+                true
+            } else {
+                !is_consonant(chars, i - 1)
+            }
+        }
+        _ => true,
+    }
+}
+
+/// The measure `m` of `chars`: the number of VC sequences after an optional
+/// leading consonant run (a word is `[C](VC)^m[V]`).
+fn measure(chars: &[char]) -> usize {
+    let mut i = 0;
+    while i < chars.len() && is_consonant(chars, i) {
+        i += 1;
+    }
+
+    let mut m = 0;
+    loop {
+        while i < chars.len() && !is_consonant(chars, i) {
+            i += 1;
+        }
+        if i >= chars.len() {
+            break;
+        }
+        while i < chars.len() && is_consonant(chars, i) {
+            i += 1;
+        }
+        m += 1;
+        if i >= chars.len() {
+            break;
+        }
+    }
+    m
+}
+
+fn contains_vowel(chars: &[char]) -> bool {
+    (0..chars.len()).any(|i| !is_consonant(chars, i))
+}
+
+fn ends_double_consonant(chars: &[char]) -> bool {
+    let n = chars.len();
+    n >= 2 && chars[n - 1] == chars[n - 2] && is_consonant(chars, n - 1)
+}
+
+/// Stem ends in consonant-vowel-consonant, where the final consonant isn't
+/// w, x, or y (Porter's "cvc" condition, used to decide whether to re-add a
+/// trailing `e` after stripping `-ed`/`-ing`).
+fn ends_cvc(chars: &[char]) -> bool {
+    let n = chars.len();
+    if n < 3 {
+        return false;
+    }
+    is_consonant(chars, n - 3)
+        && !is_consonant(chars, n - 2)
+        && is_consonant(chars, n - 1)
+        && !matches!(chars[n - 1], 'w' | 'x' | 'y')
+}
+
+fn ends_with(chars: &[char], suffix: &str) -> bool {
+    let suffix: Vec<char> = suffix.chars().collect();
+    chars.len() >= suffix.len() && chars[chars.len() - suffix.len()..] == suffix[..]
+}
+
+fn strip(chars: &[char], n: usize) -> Vec<char> {
+    chars[..chars.len() - n].to_vec()
+}
+
+fn replace_suffix(chars: Vec<char>, suffix: &str, replacement: &str) -> Vec<char> {
+    if ends_with(&chars, suffix) {
+        let mut stem = strip(&chars, suffix.chars().count());
+        stem.extend(replacement.chars());
+        stem
+    } else {
+        chars
+    }
+}
+
+/// Try each `(suffix, condition)` pair in order on the stem left after
+/// removing `suffix`; apply the first whose `condition(stem_measure)` holds.
+fn apply_measured_rule(
+    chars: Vec<char>,
+    rules: &[(&str, &str, fn(usize) -> bool)],
+) -> Vec<char> {
+    for (suffix, replacement, condition) in rules {
+        if ends_with(&chars, suffix) {
+            let stem = strip(&chars, suffix.chars().count());
+            if condition(measure(&stem)) {
+                let mut result = stem;
+                result.extend(replacement.chars());
+                return result;
+            }
+            // A suffix only matches one rule; once found (even if its
+            // condition fails) Porter's algorithm stops trying the rest.
+            return chars;
+        }
+    }
+    chars
+}
+
+fn step1a(chars: Vec<char>) -> Vec<char> {
+    if ends_with(&chars, "sses") {
+        replace_suffix(chars, "sses", "ss")
+    } else if ends_with(&chars, "ies") {
+        replace_suffix(chars, "ies", "i")
+    } else if ends_with(&chars, "ss") {
+        chars
+    } else if ends_with(&chars, "s") && chars.len() > 1 {
+        strip(&chars, 1)
+    } else {
+        chars
+    }
+}
+
+fn step1b(chars: Vec<char>) -> Vec<char> {
+    if ends_with(&chars, "eed") {
+        let stem = strip(&chars, 3);
+        if measure(&stem) > 0 {
+            let mut result = stem;
+            result.push('e');
+            result.push('e');
+            return result;
+        }
+        return chars;
+    }
+
+    let (stripped, did_strip) = if ends_with(&chars, "ed") {
+        let stem = strip(&chars, 2);
+        (contains_vowel(&stem).then_some(stem), true)
+    } else if ends_with(&chars, "ing") {
+        let stem = strip(&chars, 3);
+        (contains_vowel(&stem).then_some(stem), true)
+    } else {
+        (None, false)
+    };
+
+    if !did_strip {
+        return chars;
+    }
+    let Some(mut stem) = stripped else {
+        return chars;
+    };
+
+    if ends_with(&stem, "at") || ends_with(&stem, "bl") || ends_with(&stem, "iz") {
+        stem.push('e');
+    } else if ends_double_consonant(&stem) && !matches!(stem.last(), Some('l' | 's' | 'z')) {
+        stem.pop();
+    } else if measure(&stem) == 1 && ends_cvc(&stem) {
+        stem.push('e');
+    }
+
+    stem
+}
+
+fn step1c(chars: Vec<char>) -> Vec<char> {
+    if ends_with(&chars, "y") {
+        let stem = strip(&chars, 1);
+        if contains_vowel(&stem) {
+            let mut result = stem;
+            result.push('i');
+            return result;
+        }
+    }
+    chars
+}
+
+fn step2(chars: Vec<char>) -> Vec<char> {
+    const RULES: &[(&str, &str, fn(usize) -> bool)] = &[
+        ("ational", "ate", |m| m > 0),
+        ("tional", "tion", |m| m > 0),
+        ("enci", "ence", |m| m > 0),
+        ("anci", "ance", |m| m > 0),
+        ("izer", "ize", |m| m > 0),
+        ("abli", "able", |m| m > 0),
+        ("alli", "al", |m| m > 0),
+        ("entli", "ent", |m| m > 0),
+        ("eli", "e", |m| m > 0),
+        ("ousli", "ous", |m| m > 0),
+        ("ization", "ize", |m| m > 0),
+        ("ation", "ate", |m| m > 0),
+        ("ator", "ate", |m| m > 0),
+        ("alism", "al", |m| m > 0),
+        ("iveness", "ive", |m| m > 0),
+        ("fulness", "ful", |m| m > 0),
+        ("ousness", "ous", |m| m > 0),
+        ("aliti", "al", |m| m > 0),
+        ("iviti", "ive", |m| m > 0),
+        ("biliti", "ble", |m| m > 0),
+    ];
+    apply_measured_rule(chars, RULES)
+}
+
+fn step3(chars: Vec<char>) -> Vec<char> {
+    const RULES: &[(&str, &str, fn(usize) -> bool)] = &[
+        ("icate", "ic", |m| m > 0),
+        ("ative", "", |m| m > 0),
+        ("alize", "al", |m| m > 0),
+        ("iciti", "ic", |m| m > 0),
+        ("ical", "ic", |m| m > 0),
+        ("ful", "", |m| m > 0),
+        ("ness", "", |m| m > 0),
+    ];
+    apply_measured_rule(chars, RULES)
+}
+
+fn step4(chars: Vec<char>) -> Vec<char> {
+    // Step 4's "ion" rule additionally requires the stem to end in 's' or
+    // 't', so it's handled separately from the plain suffix table.
+    if ends_with(&chars, "ion") {
+        let stem = strip(&chars, 3);
+        if measure(&stem) > 1 && matches!(stem.last(), Some('s' | 't')) {
+            return stem;
+        }
+    }
+
+    const RULES: &[(&str, &str, fn(usize) -> bool)] = &[
+        ("al", "", |m| m > 1),
+        ("ance", "", |m| m > 1),
+        ("ence", "", |m| m > 1),
+        ("er", "", |m| m > 1),
+        ("ic", "", |m| m > 1),
+        ("able", "", |m| m > 1),
+        ("ible", "", |m| m > 1),
+        ("ant", "", |m| m > 1),
+        ("ement", "", |m| m > 1),
+        ("ment", "", |m| m > 1),
+        ("ent", "", |m| m > 1),
+        ("ou", "", |m| m > 1),
+        ("ism", "", |m| m > 1),
+        ("ate", "", |m| m > 1),
+        ("iti", "", |m| m > 1),
+        ("ous", "", |m| m > 1),
+        ("ive", "", |m| m > 1),
+        ("ize", "", |m| m > 1),
+    ];
+    apply_measured_rule(chars, RULES)
+}
+
+fn step5a(chars: Vec<char>) -> Vec<char> {
+    if ends_with(&chars, "e") {
+        let stem = strip(&chars, 1);
+        let m = measure(&stem);
+        if m > 1 || (m == 1 && !ends_cvc(&stem)) {
+            return stem;
+        }
+    }
+    chars
+}
+
+fn step5b(chars: Vec<char>) -> Vec<char> {
+    if measure(&chars) > 1 && ends_double_consonant(&chars) && chars.last() == Some(&'l') {
+        return strip(&chars, 1);
+    }
+    chars
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stem_collapses_running_and_runs() {
+        assert_eq!(stem("running"), stem("runs"));
+        assert_eq!(stem("running"), "run");
+    }
+
+    #[test]
+    fn test_stem_handles_plurals() {
+        assert_eq!(stem("caresses"), "caress");
+        assert_eq!(stem("ponies"), "poni");
+    }
+
+    #[test]
+    fn test_stem_handles_ational_suffix() {
+        // Step 2 maps "ational" -> "ate" ("relate"); step 5a then drops the
+        // final "e" since the stem "relat" has m = 2.
+        assert_eq!(stem("relational"), "relat");
+    }
+
+    #[test]
+    fn test_stem_short_word_is_unchanged() {
+        assert_eq!(stem("go"), "go");
+    }
+
+    #[test]
+    fn test_measure_examples_from_porters_paper() {
+        // TR, EE, TREE, Y, BY -> m = 0; TROUBLE, OATS, TREES, IVY -> m = 1
+        assert_eq!(measure(&['t', 'r']), 0);
+        assert_eq!(measure(&['t', 'r', 'e', 'e']), 0);
+        assert_eq!(measure(&['t', 'r', 'o', 'u', 'b', 'l', 'e']), 1);
+        assert_eq!(measure(&['o', 'a', 't', 's']), 1);
+        assert_eq!(
+            measure(&['p', 'r', 'i', 'v', 'a', 't', 'e']),
+            2
+        );
+    }
+}
@@ -0,0 +1,378 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use tracing::debug;
+
+use crate::embedding;
+
+/// Default token ceiling a single embedding call tolerates before truncating
+/// silently (mirrors `embedding::model::MAX_LENGTH`).
+const MAX_LENGTH: usize = 512;
+
+/// A token-bounded slice of a longer document, carrying enough provenance to
+/// map a search hit back to a location in the original text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chunk {
+    pub document_id: String,
+    pub ordinal: usize,
+    pub text: String,
+    /// Half-open byte range `[start_byte, end_byte)` this chunk was sourced
+    /// from, suitable for indexing directly into the original `str`. Not a
+    /// char count — multi-byte UTF-8 input means these offsets don't line up
+    /// with a `chars()` index.
+    pub start_byte: usize,
+    pub end_byte: usize,
+}
+
+/// Configuration for `chunk_document`.
+#[derive(Debug, Clone, Copy)]
+pub struct ChunkerConfig {
+    /// Maximum tokens per chunk.
+    pub max_tokens: usize,
+    /// Tokens of overlap carried into the next chunk when the sliding-window
+    /// fallback has to split a single oversized section.
+    pub overlap_tokens: usize,
+}
+
+impl Default for ChunkerConfig {
+    fn default() -> Self {
+        Self {
+            max_tokens: MAX_LENGTH,
+            overlap_tokens: 64,
+        }
+    }
+}
+
+static PARAGRAPH_BREAK: Lazy<Regex> = Lazy::new(|| Regex::new(r"\n[ \t]*\n+").unwrap());
+static SECTION_HEADER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?m)^(#{1,6}\s.+|[A-Z][A-Z0-9 /&\t-]{3,}:?)[ \t]*$").unwrap());
+static SENTENCE_BREAK: Lazy<Regex> = Lazy::new(|| Regex::new(r"[.!?]+\s+").unwrap());
+
+/// Split `text` into overlapping, token-bounded chunks.
+///
+/// Prefers splitting on structural boundaries (blank lines, section headers)
+/// so related content stays together, packing consecutive sections into a
+/// chunk until `max_tokens` would be exceeded. A section that alone exceeds
+/// `max_tokens` is further split on sentence ends, and a single run-on
+/// sentence too long for one chunk falls back to a sliding token window with
+/// `overlap_tokens` of overlap. This keeps long resumes and job descriptions
+/// from being silently truncated at the embedding model's token limit.
+pub fn chunk_document(document_id: &str, text: &str, config: &ChunkerConfig) -> Vec<Chunk> {
+    let mut chunks = Vec::new();
+    let mut ordinal = 0;
+
+    let sections = split_sections(text);
+
+    // Greedily pack consecutive sections into a chunk bounded by max_tokens.
+    let mut buf_start: Option<usize> = None;
+    let mut buf_end = 0usize;
+    let mut buf_tokens = 0usize;
+
+    for (section, sec_start, sec_end) in sections {
+        let section_tokens = embedding::count_tokens(section);
+
+        if section_tokens > config.max_tokens {
+            if let Some(start) = buf_start.take() {
+                push_trimmed(&mut chunks, &mut ordinal, document_id, text, start, buf_end);
+                buf_tokens = 0;
+            }
+            for (sub_text, sub_start, sub_end) in split_oversized(section, sec_start, config) {
+                chunks.push(Chunk {
+                    document_id: document_id.to_string(),
+                    ordinal,
+                    text: sub_text,
+                    start_byte: sub_start,
+                    end_byte: sub_end,
+                });
+                ordinal += 1;
+            }
+            continue;
+        }
+
+        if buf_start.is_some() && buf_tokens + section_tokens > config.max_tokens {
+            let start = buf_start.take().unwrap();
+            push_trimmed(&mut chunks, &mut ordinal, document_id, text, start, buf_end);
+            buf_tokens = 0;
+        }
+
+        if buf_start.is_none() {
+            buf_start = Some(sec_start);
+        }
+        buf_end = sec_end;
+        buf_tokens += section_tokens;
+    }
+
+    if let Some(start) = buf_start {
+        push_trimmed(&mut chunks, &mut ordinal, document_id, text, start, buf_end);
+    }
+
+    debug!(
+        "Chunked document '{}' ({} chars) into {} chunks",
+        document_id,
+        text.len(),
+        chunks.len()
+    );
+
+    chunks
+}
+
+/// Trim whitespace off `text[start..end]` and, if anything remains, push it
+/// as the next chunk with offsets adjusted to match the trimmed text.
+fn push_trimmed(
+    chunks: &mut Vec<Chunk>,
+    ordinal: &mut usize,
+    document_id: &str,
+    text: &str,
+    start: usize,
+    end: usize,
+) {
+    if start >= end {
+        return;
+    }
+    let slice = &text[start..end];
+    let trimmed = slice.trim();
+    if trimmed.is_empty() {
+        return;
+    }
+    let leading = slice.len() - slice.trim_start().len();
+    let trim_start = start + leading;
+    let trim_end = trim_start + trimmed.len();
+
+    chunks.push(Chunk {
+        document_id: document_id.to_string(),
+        ordinal: *ordinal,
+        text: trimmed.to_string(),
+        start_byte: trim_start,
+        end_byte: trim_end,
+    });
+    *ordinal += 1;
+}
+
+/// Split `text` into structural units (paragraphs further split on section
+/// headers), returning `(unit, start_byte, end_byte)` for each non-empty span.
+fn split_sections(text: &str) -> Vec<(&str, usize, usize)> {
+    let mut units = Vec::new();
+
+    let mut para_cursor = 0;
+    for m in PARAGRAPH_BREAK.find_iter(text) {
+        split_on_headers(&text[para_cursor..m.start()], para_cursor, &mut units);
+        para_cursor = m.end();
+    }
+    split_on_headers(&text[para_cursor..text.len()], para_cursor, &mut units);
+
+    units
+}
+
+/// Within one paragraph, break out section headers (e.g. markdown `#`
+/// headings or short ALL-CAPS lines) as their own unit so they stay attached
+/// to whichever neighboring chunk has room, rather than forcing a split.
+fn split_on_headers<'a>(paragraph: &'a str, offset: usize, units: &mut Vec<(&'a str, usize, usize)>) {
+    if paragraph.trim().is_empty() {
+        return;
+    }
+
+    let mut cursor = 0;
+    for m in SECTION_HEADER.find_iter(paragraph) {
+        if m.start() > cursor {
+            units.push((&paragraph[cursor..m.start()], offset + cursor, offset + m.start()));
+        }
+        units.push((&paragraph[m.start()..m.end()], offset + m.start(), offset + m.end()));
+        cursor = m.end();
+    }
+    if cursor < paragraph.len() {
+        units.push((&paragraph[cursor..], offset + cursor, offset + paragraph.len()));
+    }
+}
+
+/// Split a section that alone exceeds `max_tokens` on sentence boundaries,
+/// greedily packing sentences into chunks; a single sentence still too long
+/// falls back to a sliding token window with `overlap_tokens` of overlap.
+fn split_oversized(section: &str, offset: usize, config: &ChunkerConfig) -> Vec<(String, usize, usize)> {
+    let mut result = Vec::new();
+
+    let mut sentences: Vec<(usize, usize)> = Vec::new();
+    let mut cursor = 0;
+    for m in SENTENCE_BREAK.find_iter(section) {
+        sentences.push((cursor, m.end()));
+        cursor = m.end();
+    }
+    if cursor < section.len() {
+        sentences.push((cursor, section.len()));
+    }
+
+    let mut buf_start: Option<usize> = None;
+    let mut buf_end = 0usize;
+    let mut buf_tokens = 0usize;
+
+    for (s_start, s_end) in sentences {
+        let sentence = &section[s_start..s_end];
+        let sentence_tokens = embedding::count_tokens(sentence);
+
+        if sentence_tokens > config.max_tokens {
+            if let Some(start) = buf_start.take() {
+                flush_sliding(&mut result, section, offset, start, buf_end);
+                buf_tokens = 0;
+            }
+            result.extend(sliding_window(sentence, offset + s_start, config));
+            continue;
+        }
+
+        if buf_start.is_some() && buf_tokens + sentence_tokens > config.max_tokens {
+            let start = buf_start.take().unwrap();
+            flush_sliding(&mut result, section, offset, start, buf_end);
+            buf_tokens = 0;
+        }
+
+        if buf_start.is_none() {
+            buf_start = Some(s_start);
+        }
+        buf_end = s_end;
+        buf_tokens += sentence_tokens;
+    }
+
+    if let Some(start) = buf_start {
+        flush_sliding(&mut result, section, offset, start, buf_end);
+    }
+
+    result
+}
+
+fn flush_sliding(
+    result: &mut Vec<(String, usize, usize)>,
+    section: &str,
+    offset: usize,
+    start: usize,
+    end: usize,
+) {
+    if start >= end {
+        return;
+    }
+    let slice = &section[start..end];
+    let trimmed = slice.trim();
+    if trimmed.is_empty() {
+        return;
+    }
+    let leading = slice.len() - slice.trim_start().len();
+    let trim_start = offset + start + leading;
+    let trim_end = trim_start + trimmed.len();
+    result.push((trimmed.to_string(), trim_start, trim_end));
+}
+
+/// Last-resort split of a single run-on span into overlapping windows bounded
+/// by `max_tokens`, since there's no remaining structural boundary to split
+/// on. Windows are grown word-by-word but measured with `embedding::count_tokens`
+/// per word rather than a raw word count, so a run of multi-token words can't
+/// push a window past the model's actual token limit the way a word-count
+/// bound would.
+fn sliding_window(text: &str, offset: usize, config: &ChunkerConfig) -> Vec<(String, usize, usize)> {
+    let words: Vec<(&str, usize, usize)> = text
+        .split_whitespace()
+        .map(|w| {
+            // Recover the word's byte offset within `text` via pointer arithmetic.
+            let start = w.as_ptr() as usize - text.as_ptr() as usize;
+            (w, start, start + w.len())
+        })
+        .collect();
+
+    if words.is_empty() {
+        return vec![];
+    }
+
+    let word_tokens: Vec<usize> = words
+        .iter()
+        .map(|(w, _, _)| embedding::count_tokens(w).max(1))
+        .collect();
+
+    let max_tokens = config.max_tokens.max(1);
+    let overlap_tokens = config.overlap_tokens.min(max_tokens.saturating_sub(1));
+
+    let mut result = Vec::new();
+    let mut i = 0;
+    while i < words.len() {
+        // Grow the window word-by-word until the next word would push the
+        // running token total past max_tokens. Always take at least one word,
+        // even if it alone is over budget, so the loop can't stall.
+        let mut end_idx = i;
+        let mut tokens = 0usize;
+        while end_idx < words.len() {
+            let next = word_tokens[end_idx];
+            if tokens > 0 && tokens + next > max_tokens {
+                break;
+            }
+            tokens += next;
+            end_idx += 1;
+        }
+
+        let (_, start_byte, _) = words[i];
+        let (_, _, end_byte) = words[end_idx - 1];
+
+        result.push((
+            text[start_byte..end_byte].to_string(),
+            offset + start_byte,
+            offset + end_byte,
+        ));
+
+        if end_idx >= words.len() {
+            break;
+        }
+
+        // Step back by roughly overlap_tokens worth of words for the next window.
+        let mut back = 0usize;
+        let mut back_tokens = 0usize;
+        while back < end_idx - i && back_tokens < overlap_tokens {
+            back += 1;
+            back_tokens += word_tokens[end_idx - back];
+        }
+        let stride = (end_idx - i).saturating_sub(back).max(1);
+        i += stride;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunk_short_document_is_single_chunk() {
+        let config = ChunkerConfig::default();
+        let text = "Experienced backend engineer with Rust and Go.";
+        let chunks = chunk_document("doc-1", text, &config);
+
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].document_id, "doc-1");
+        assert_eq!(chunks[0].ordinal, 0);
+        assert_eq!(&text[chunks[0].start_byte..chunks[0].end_byte], chunks[0].text);
+    }
+
+    #[test]
+    fn test_chunk_splits_on_blank_lines() {
+        let config = ChunkerConfig {
+            max_tokens: 6,
+            overlap_tokens: 1,
+        };
+        let text = "Summary line one two\n\nEXPERIENCE\n\nBuilt things for five years total";
+        let chunks = chunk_document("doc-2", text, &config);
+
+        assert!(chunks.len() > 1);
+        for chunk in &chunks {
+            assert_eq!(&text[chunk.start_byte..chunk.end_byte], chunk.text);
+        }
+    }
+
+    #[test]
+    fn test_oversized_sentence_falls_back_to_sliding_window() {
+        let config = ChunkerConfig {
+            max_tokens: 4,
+            overlap_tokens: 1,
+        };
+        let text = "one two three four five six seven eight nine ten";
+        let chunks = chunk_document("doc-3", text, &config);
+
+        assert!(chunks.len() > 1);
+        for chunk in &chunks {
+            assert!(embedding::count_tokens(&chunk.text) <= config.max_tokens);
+            assert_eq!(&text[chunk.start_byte..chunk.end_byte], chunk.text);
+        }
+    }
+}
@@ -28,6 +28,38 @@ pub struct ModelsConfig {
     pub embedding_model: String,
     pub reranker_model: String,
     pub models_dir: String,
+    /// Max number of embeddings kept in the in-memory LRU cache.
+    pub embedding_cache_capacity: usize,
+    /// Optional on-disk sidecar file for the embedding cache, so it survives restarts.
+    #[serde(default)]
+    pub embedding_cache_path: Option<String>,
+    /// Total token budget per micro-batch for the `EmbeddingQueue`.
+    pub embedding_queue_token_budget: usize,
+    /// How long the `EmbeddingQueue` waits for more submissions before flushing a partial batch.
+    pub embedding_queue_debounce_ms: u64,
+    /// Which `EmbeddingProvider` to use: "onnx" for the local model, or "http" for an
+    /// OpenAI-compatible (or Ollama) embeddings endpoint.
+    pub provider: String,
+    /// Base URL of the remote embeddings endpoint, e.g. `https://api.openai.com/v1/embeddings`.
+    #[serde(default)]
+    pub embedding_endpoint: Option<String>,
+    /// API key sent as a bearer token to the remote embeddings endpoint, if required.
+    #[serde(default)]
+    pub embedding_api_key: Option<String>,
+    /// Embedding dimensionality to report for the http provider, which can't introspect
+    /// it from a model file the way the ONNX provider does.
+    #[serde(default)]
+    pub embedding_dimensions: Option<usize>,
+    /// Prompt template rendered against a document's metadata (plus its `content`)
+    /// before embedding, e.g. `"{{title}}\n{{skills}}\n{{content}}"`. A line whose
+    /// placeholders all resolve to an empty value is omitted from the rendered text.
+    pub embedding_template: String,
+    /// Mean subtracted from a reranker logit before calibration, so the
+    /// sigmoid is centered on this model's typical score rather than 0.
+    pub reranker_calibration_mean: f32,
+    /// Standard deviation a reranker logit is scaled by before calibration.
+    /// 1.0 (with mean 0.0) reduces to a plain sigmoid.
+    pub reranker_calibration_sigma: f32,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -37,6 +69,12 @@ pub struct SearchConfig {
     pub bm25_weight: f32,
     pub rrf_k: usize,
     pub rerank_fetch_multiplier: usize,
+    /// Minimum Qdrant cosine score a vector hit must clear to be considered at all;
+    /// applied before fusion since vector and BM25 scores live on different scales.
+    pub rag_min_score_vector: f32,
+    /// Minimum BM25 score a keyword hit must clear to be considered at all;
+    /// applied before fusion since vector and BM25 scores live on different scales.
+    pub rag_min_score_text: f32,
 }
 
 impl Config {
@@ -55,11 +93,20 @@ impl Config {
             .set_default("models.embedding_model", "all-MiniLM-L6-v2")?
             .set_default("models.reranker_model", "ms-marco-MiniLM-L-6-v2")?
             .set_default("models.models_dir", "./models")?
+            .set_default("models.embedding_cache_capacity", 10_000)?
+            .set_default("models.embedding_queue_token_budget", 16_000)?
+            .set_default("models.embedding_queue_debounce_ms", 10)?
+            .set_default("models.provider", "onnx")?
+            .set_default("models.embedding_template", "{{title}}\n{{skills}}\n{{content}}")?
+            .set_default("models.reranker_calibration_mean", 0.0)?
+            .set_default("models.reranker_calibration_sigma", 1.0)?
             .set_default("search.default_top_k", 10)?
             .set_default("search.vector_weight", 0.7)?
             .set_default("search.bm25_weight", 0.3)?
             .set_default("search.rrf_k", 60)?
             .set_default("search.rerank_fetch_multiplier", 5)?
+            .set_default("search.rag_min_score_vector", 0.0)?
+            .set_default("search.rag_min_score_text", 0.0)?
             // Load from environment
             .add_source(
                 config::Environment::default()
@@ -99,6 +146,35 @@ impl Config {
                 reranker_model: env::var("RERANKER_MODEL")
                     .unwrap_or_else(|_| "ms-marco-MiniLM-L-6-v2".to_string()),
                 models_dir: env::var("MODELS_DIR").unwrap_or_else(|_| "./models".to_string()),
+                embedding_cache_capacity: env::var("EMBEDDING_CACHE_CAPACITY")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(10_000),
+                embedding_cache_path: env::var("EMBEDDING_CACHE_PATH").ok(),
+                embedding_queue_token_budget: env::var("EMBEDDING_QUEUE_TOKEN_BUDGET")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(16_000),
+                embedding_queue_debounce_ms: env::var("EMBEDDING_QUEUE_DEBOUNCE_MS")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(10),
+                provider: env::var("EMBEDDING_PROVIDER").unwrap_or_else(|_| "onnx".to_string()),
+                embedding_endpoint: env::var("EMBEDDING_ENDPOINT").ok(),
+                embedding_api_key: env::var("EMBEDDING_API_KEY").ok(),
+                embedding_dimensions: env::var("EMBEDDING_DIMENSIONS")
+                    .ok()
+                    .and_then(|s| s.parse().ok()),
+                embedding_template: env::var("EMBEDDING_TEMPLATE")
+                    .unwrap_or_else(|_| "{{title}}\n{{skills}}\n{{content}}".to_string()),
+                reranker_calibration_mean: env::var("RERANKER_CALIBRATION_MEAN")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(0.0),
+                reranker_calibration_sigma: env::var("RERANKER_CALIBRATION_SIGMA")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(1.0),
             },
             search: SearchConfig {
                 default_top_k: env::var("DEFAULT_TOP_K")
@@ -121,6 +197,14 @@ impl Config {
                     .ok()
                     .and_then(|s| s.parse().ok())
                     .unwrap_or(5),
+                rag_min_score_vector: env::var("RAG_MIN_SCORE_VECTOR")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(0.0),
+                rag_min_score_text: env::var("RAG_MIN_SCORE_TEXT")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(0.0),
             },
         }
     }
@@ -0,0 +1,231 @@
+use anyhow::{Context, Result};
+use parking_lot::Mutex;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::fs::{File, OpenOptions};
+use std::hash::{Hash, Hasher};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use tracing::{debug, warn};
+
+/// Compute the cache key for a piece of text embedded by a given model.
+///
+/// The key is a hash of the normalized text plus the model name, so the same
+/// text embedded by two different models never collides.
+pub fn content_key(model_name: &str, text: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    model_name.hash(&mut hasher);
+    normalize(text).hash(&mut hasher);
+    hasher.finish()
+}
+
+fn normalize(text: &str) -> String {
+    text.trim().to_string()
+}
+
+/// Bounded in-memory LRU cache of `content hash -> embedding vector`, with an
+/// optional on-disk sidecar so the cache survives restarts.
+///
+/// The sidecar is a simple append-only file of fixed-width records
+/// (`hash: u64`, `dim: u32`, `dim * f32`); it is replayed into memory on
+/// startup and appended to on every miss that gets filled.
+pub struct EmbeddingCache {
+    capacity: usize,
+    sidecar_path: Option<PathBuf>,
+    inner: Mutex<Inner>,
+}
+
+struct Inner {
+    map: HashMap<u64, Vec<f32>>,
+    order: VecDeque<u64>,
+    sidecar: Option<File>,
+}
+
+impl EmbeddingCache {
+    /// Create a cache with the given capacity, optionally backed by an
+    /// on-disk sidecar file. Existing sidecar contents are loaded eagerly.
+    pub fn new(capacity: usize, sidecar_path: Option<&Path>) -> Result<Self> {
+        let mut map = HashMap::new();
+        let mut order = VecDeque::new();
+
+        if let Some(path) = sidecar_path {
+            if path.exists() {
+                load_sidecar(path, &mut map, &mut order, capacity)
+                    .context("Failed to load embedding cache sidecar")?;
+                debug!(
+                    "Loaded {} cached embeddings from sidecar {:?}",
+                    map.len(),
+                    path
+                );
+            }
+        }
+
+        let sidecar = match sidecar_path {
+            Some(path) => Some(
+                OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .context("Failed to open embedding cache sidecar for appending")?,
+            ),
+            None => None,
+        };
+
+        Ok(Self {
+            capacity,
+            sidecar_path: sidecar_path.map(PathBuf::from),
+            inner: Mutex::new(Inner {
+                map,
+                order,
+                sidecar,
+            }),
+        })
+    }
+
+    /// Look up a cached embedding by its content hash.
+    pub fn get(&self, key: u64) -> Option<Vec<f32>> {
+        let mut inner = self.inner.lock();
+        if let Some(vector) = inner.map.get(&key).cloned() {
+            inner.order.retain(|k| *k != key);
+            inner.order.push_back(key);
+            Some(vector)
+        } else {
+            None
+        }
+    }
+
+    /// Insert a freshly computed embedding, evicting the least-recently-used
+    /// entry if the cache is at capacity, and appending to the sidecar file
+    /// if one is configured.
+    pub fn insert(&self, key: u64, vector: Vec<f32>) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut inner = self.inner.lock();
+
+        if !inner.map.contains_key(&key) && inner.map.len() >= self.capacity {
+            if let Some(oldest) = inner.order.pop_front() {
+                inner.map.remove(&oldest);
+            }
+        }
+
+        inner.order.retain(|k| *k != key);
+        inner.order.push_back(key);
+
+        if let Some(sidecar) = inner.sidecar.as_mut() {
+            if let Err(e) = append_record(sidecar, key, &vector) {
+                warn!("Failed to persist embedding cache entry: {}", e);
+            }
+        }
+
+        inner.map.insert(key, vector);
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.lock().map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn sidecar_path(&self) -> Option<&Path> {
+        self.sidecar_path.as_deref()
+    }
+}
+
+fn append_record(file: &mut File, key: u64, vector: &[f32]) -> Result<()> {
+    file.write_all(&key.to_le_bytes())?;
+    file.write_all(&(vector.len() as u32).to_le_bytes())?;
+    for x in vector {
+        file.write_all(&x.to_le_bytes())?;
+    }
+    file.flush()?;
+    Ok(())
+}
+
+fn load_sidecar(
+    path: &Path,
+    map: &mut HashMap<u64, Vec<f32>>,
+    order: &mut VecDeque<u64>,
+    capacity: usize,
+) -> Result<()> {
+    let mut file = File::open(path)?;
+    let mut buf = Vec::new();
+    file.read_to_end(&mut buf)?;
+
+    let mut offset = 0;
+    while offset + 12 <= buf.len() {
+        let key = u64::from_le_bytes(buf[offset..offset + 8].try_into().unwrap());
+        let dim = u32::from_le_bytes(buf[offset + 8..offset + 12].try_into().unwrap()) as usize;
+        offset += 12;
+
+        let end = offset + dim * 4;
+        if end > buf.len() {
+            warn!("Truncated record in embedding cache sidecar, stopping replay");
+            break;
+        }
+
+        let vector: Vec<f32> = buf[offset..end]
+            .chunks_exact(4)
+            .map(|c| f32::from_le_bytes(c.try_into().unwrap()))
+            .collect();
+        offset = end;
+
+        if !map.contains_key(&key) && map.len() >= capacity {
+            if let Some(oldest) = order.pop_front() {
+                map.remove(&oldest);
+            }
+        }
+        order.retain(|k| *k != key);
+        order.push_back(key);
+        map.insert(key, vector);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_key_stable_for_same_input() {
+        let a = content_key("model-a", "hello world");
+        let b = content_key("model-a", "hello world");
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_content_key_differs_by_model() {
+        let a = content_key("model-a", "hello world");
+        let b = content_key("model-b", "hello world");
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_insert_and_get() {
+        let cache = EmbeddingCache::new(4, None).unwrap();
+        let key = content_key("m", "text");
+        assert!(cache.get(key).is_none());
+
+        cache.insert(key, vec![1.0, 2.0]);
+        assert_eq!(cache.get(key), Some(vec![1.0, 2.0]));
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let cache = EmbeddingCache::new(2, None).unwrap();
+        cache.insert(1, vec![1.0]);
+        cache.insert(2, vec![2.0]);
+        // Touch 1 so it's more recently used than 2
+        assert!(cache.get(1).is_some());
+
+        cache.insert(3, vec![3.0]);
+
+        assert!(cache.get(1).is_some());
+        assert!(cache.get(2).is_none());
+        assert!(cache.get(3).is_some());
+    }
+}
@@ -0,0 +1,186 @@
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::{debug, warn};
+
+use super::provider::EmbeddingProvider;
+use crate::config::ModelsConfig;
+
+const MAX_RETRIES: u32 = 5;
+const BASE_BACKOFF_MS: u64 = 200;
+const MAX_BACKOFF_MS: u64 = 8_000;
+
+/// Embedding provider speaking the OpenAI `/v1/embeddings` request/response
+/// shape. Ollama's embeddings endpoint accepts the same request body, so
+/// this also works unmodified against a local Ollama instance.
+pub struct HttpEmbeddingProvider {
+    client: reqwest::Client,
+    endpoint: String,
+    api_key: Option<String>,
+    model_name: String,
+    dim: usize,
+}
+
+#[derive(Serialize)]
+struct EmbeddingsRequest<'a> {
+    model: &'a str,
+    input: &'a [String],
+}
+
+#[derive(Deserialize)]
+struct EmbeddingsResponse {
+    data: Vec<EmbeddingDatum>,
+}
+
+#[derive(Deserialize)]
+struct EmbeddingDatum {
+    embedding: Vec<f32>,
+    index: usize,
+}
+
+impl HttpEmbeddingProvider {
+    pub fn new(config: &ModelsConfig) -> Result<Self> {
+        let endpoint = config.embedding_endpoint.clone().ok_or_else(|| {
+            anyhow!("models.embedding_endpoint is required for the http embedding provider")
+        })?;
+
+        Ok(Self {
+            client: reqwest::Client::new(),
+            endpoint,
+            api_key: config.embedding_api_key.clone(),
+            model_name: config.embedding_model.clone(),
+            dim: config.embedding_dimensions.unwrap_or(384),
+        })
+    }
+
+    async fn request_embeddings(&self, texts: &[String]) -> Result<EmbeddingsResponse> {
+        let body = EmbeddingsRequest {
+            model: &self.model_name,
+            input: texts,
+        };
+
+        let mut attempt = 0;
+        loop {
+            let mut request = self.client.post(&self.endpoint).json(&body);
+            if let Some(key) = &self.api_key {
+                request = request.bearer_auth(key);
+            }
+
+            let response = match request.send().await {
+                Ok(response) => response,
+                Err(e) if attempt < MAX_RETRIES => {
+                    attempt += 1;
+                    let delay = backoff_delay(attempt);
+                    warn!("Embedding request failed ({}), retrying in {:?}", e, delay);
+                    tokio::time::sleep(delay).await;
+                    continue;
+                }
+                Err(e) => return Err(e).context("Embedding HTTP request failed"),
+            };
+
+            let status = response.status();
+            if status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error() {
+                if attempt >= MAX_RETRIES {
+                    return Err(anyhow!(
+                        "Embedding HTTP request failed after {} retries: {}",
+                        attempt,
+                        status
+                    ));
+                }
+                let delay = retry_after(&response).unwrap_or_else(|| backoff_delay(attempt + 1));
+                attempt += 1;
+                warn!(
+                    "Embedding endpoint returned {}, retrying in {:?}",
+                    status, delay
+                );
+                tokio::time::sleep(delay).await;
+                continue;
+            }
+
+            if !status.is_success() {
+                let text = response.text().await.unwrap_or_default();
+                return Err(anyhow!("Embedding endpoint returned {}: {}", status, text));
+            }
+
+            return response
+                .json::<EmbeddingsResponse>()
+                .await
+                .context("Failed to parse embeddings response");
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl EmbeddingProvider for HttpEmbeddingProvider {
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        if texts.is_empty() {
+            return Ok(vec![]);
+        }
+
+        debug!(
+            "Requesting {} embeddings from {}",
+            texts.len(),
+            self.endpoint
+        );
+
+        let mut response = self.request_embeddings(texts).await?;
+        response.data.sort_by_key(|d| d.index);
+
+        Ok(response.data.into_iter().map(|d| d.embedding).collect())
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dim
+    }
+
+    fn model_name(&self) -> &str {
+        &self.model_name
+    }
+}
+
+/// Parse the `Retry-After` header (seconds form) if the server sent one.
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// Exponential backoff with jitter, capped at `MAX_BACKOFF_MS`.
+fn backoff_delay(attempt: u32) -> Duration {
+    let exp = BASE_BACKOFF_MS
+        .saturating_mul(1u64 << attempt.min(16))
+        .min(MAX_BACKOFF_MS);
+    let half = exp / 2;
+    Duration::from_millis(half + jitter(half))
+}
+
+/// Small dependency-free jitter source seeded from the system clock; good
+/// enough to avoid thundering-herd retries without pulling in `rand`.
+fn jitter(bound: u64) -> u64 {
+    if bound == 0 {
+        return 0;
+    }
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos() as u64)
+        .unwrap_or(0);
+    nanos % (bound + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_grows_and_caps() {
+        let small = backoff_delay(1);
+        let large = backoff_delay(10);
+        assert!(large.as_millis() <= MAX_BACKOFF_MS as u128);
+        assert!(large.as_millis() >= small.as_millis());
+    }
+}
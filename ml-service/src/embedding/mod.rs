@@ -1,51 +1,156 @@
+mod cache;
+mod http_provider;
 mod model;
+mod provider;
+mod queue;
+mod template;
 
+pub use cache::EmbeddingCache;
+pub use http_provider::HttpEmbeddingProvider;
 pub use model::EmbeddingModel;
+pub use provider::EmbeddingProvider;
+pub use queue::EmbeddingQueue;
+pub use template::DocumentTemplate;
 
 use anyhow::Result;
 use once_cell::sync::OnceCell;
-use parking_lot::RwLock;
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::config::ModelsConfig;
 
-static EMBEDDING_MODEL: OnceCell<Arc<RwLock<EmbeddingModel>>> = OnceCell::new();
+static EMBEDDING_PROVIDER: OnceCell<Arc<dyn EmbeddingProvider>> = OnceCell::new();
+static DOCUMENT_TEMPLATE: OnceCell<DocumentTemplate> = OnceCell::new();
+static EMBEDDING_QUEUE: OnceCell<EmbeddingQueue> = OnceCell::new();
 
-/// Initialize the global embedding model
+/// Initialize the global embedding provider, selected by `ModelsConfig::provider`
 pub fn init_embedding_model(config: &ModelsConfig) -> Result<()> {
-    let model = EmbeddingModel::new(config)?;
-    EMBEDDING_MODEL
-        .set(Arc::new(RwLock::new(model)))
+    // Validate the embedding prompt template up front so a typo in config
+    // fails startup here rather than surfacing as a confusing embedding later.
+    let template = DocumentTemplate::parse(&config.embedding_template)?;
+    let _ = DOCUMENT_TEMPLATE.set(template);
+
+    let provider: Arc<dyn EmbeddingProvider> = match config.provider.as_str() {
+        "http" | "openai" | "ollama" => Arc::new(HttpEmbeddingProvider::new(config)?),
+        _ => Arc::new(EmbeddingModel::new(config)?),
+    };
+
+    EMBEDDING_PROVIDER
+        .set(provider)
         .map_err(|_| anyhow::anyhow!("Embedding model already initialized"))?;
+
+    // Coalesces concurrent single-text `embed` calls (query embedding, one
+    // chunk at a time during indexing) into token-budget-bounded micro-batches
+    // instead of round-tripping the provider once per text.
+    let _ = EMBEDDING_QUEUE.set(EmbeddingQueue::new(
+        config.embedding_queue_token_budget,
+        Duration::from_millis(config.embedding_queue_debounce_ms),
+    ));
+
     Ok(())
 }
 
-/// Get the global embedding model
-pub fn get_embedding_model() -> Option<Arc<RwLock<EmbeddingModel>>> {
-    EMBEDDING_MODEL.get().cloned()
+/// Get the global embedding provider
+pub fn get_embedding_provider() -> Option<Arc<dyn EmbeddingProvider>> {
+    EMBEDDING_PROVIDER.get().cloned()
+}
+
+/// Render a document's metadata and content through the configured embedding
+/// prompt template. Falls back to the raw `content` if no template has been
+/// initialized yet (e.g. in tests that skip `init_embedding_model`).
+pub fn render_document(metadata: &HashMap<String, String>, content: &str) -> String {
+    match DOCUMENT_TEMPLATE.get() {
+        Some(template) => template.render(metadata, content),
+        None => content.to_string(),
+    }
 }
 
-/// Generate embedding for a single text
+/// Generate embedding for a single text. Routed through the shared
+/// `EmbeddingQueue` when one has been initialized, so that concurrent
+/// single-text callers (query embedding, per-chunk indexing) get coalesced
+/// into token-budget-bounded batches instead of each round-tripping the
+/// provider alone.
 pub async fn embed(text: &str) -> Result<Vec<f32>> {
-    let model = get_embedding_model()
+    if text.trim().is_empty() {
+        return Err(anyhow::anyhow!("cannot embed an empty or whitespace-only text"));
+    }
+
+    if let Some(queue) = EMBEDDING_QUEUE.get() {
+        return queue.submit(text.to_string()).await;
+    }
+
+    let provider = get_embedding_provider()
         .ok_or_else(|| anyhow::anyhow!("Embedding model not initialized"))?;
 
-    let model = model.read();
-    model.embed(text)
+    let embeddings = provider.embed_batch(&[text.to_string()]).await?;
+    embeddings
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("No embedding generated"))
 }
 
-/// Generate embeddings for multiple texts
+/// Generate embeddings for multiple texts. Rejects up front rather than
+/// burning a model invocation (and skewing score distributions with a
+/// meaningless vector) if any text is empty or whitespace-only.
 pub async fn embed_batch(texts: &[String]) -> Result<Vec<Vec<f32>>> {
-    let model = get_embedding_model()
+    if texts.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    if let Some(i) = texts.iter().position(|t| t.trim().is_empty()) {
+        return Err(anyhow::anyhow!(
+            "text at index {} is empty or whitespace-only",
+            i
+        ));
+    }
+
+    let provider = get_embedding_provider()
         .ok_or_else(|| anyhow::anyhow!("Embedding model not initialized"))?;
 
-    let model = model.read();
-    model.embed_batch(texts)
+    provider.embed_batch(texts).await
 }
 
 /// Get embedding dimensions
 pub fn get_dimensions() -> usize {
-    get_embedding_model()
-        .map(|m| m.read().dimensions())
+    get_embedding_provider()
+        .map(|p| p.dimensions())
         .unwrap_or(384) // Default for MiniLM
 }
+
+/// Get the number of entries currently held in the embedding cache
+pub fn get_cache_len() -> usize {
+    get_embedding_provider().map(|p| p.cache_len()).unwrap_or(0)
+}
+
+/// Count tokens in a text without running inference (used by `EmbeddingQueue`
+/// to decide how to pack a micro-batch)
+pub fn count_tokens(text: &str) -> usize {
+    get_embedding_provider()
+        .map(|p| p.count_tokens(text))
+        .unwrap_or_else(|| text.split_whitespace().count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_embed_rejects_whitespace_only_text() {
+        let err = embed("   \n\t  ").await.unwrap_err();
+        assert!(err.to_string().contains("empty or whitespace-only"));
+    }
+
+    #[tokio::test]
+    async fn test_embed_batch_rejects_empty_entry_without_calling_provider() {
+        let texts = vec!["real text".to_string(), "  ".to_string()];
+        let err = embed_batch(&texts).await.unwrap_err();
+        assert!(err.to_string().contains("index 1"));
+    }
+
+    #[tokio::test]
+    async fn test_embed_batch_empty_input_is_a_noop() {
+        let result = embed_batch(&[]).await.unwrap();
+        assert!(result.is_empty());
+    }
+}
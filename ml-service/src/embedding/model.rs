@@ -5,6 +5,8 @@ use std::path::Path;
 use tokenizers::Tokenizer;
 use tracing::{debug, info};
 
+use super::cache::{self, EmbeddingCache};
+use super::provider::EmbeddingProvider;
 use crate::config::ModelsConfig;
 
 const MAX_LENGTH: usize = 512;
@@ -14,6 +16,7 @@ pub struct EmbeddingModel {
     tokenizer: Tokenizer,
     model_name: String,
     dim: usize,
+    cache: EmbeddingCache,
 }
 
 impl EmbeddingModel {
@@ -51,11 +54,18 @@ impl EmbeddingModel {
             config.embedding_model, dim
         );
 
+        let cache = EmbeddingCache::new(
+            config.embedding_cache_capacity,
+            config.embedding_cache_path.as_deref().map(Path::new),
+        )
+        .context("Failed to initialize embedding cache")?;
+
         Ok(Self {
             session,
             tokenizer,
             model_name: config.embedding_model.clone(),
             dim,
+            cache,
         })
     }
 
@@ -80,8 +90,59 @@ impl EmbeddingModel {
             return Ok(vec![]);
         }
 
-        debug!("Embedding batch of {} texts", texts.len());
+        // Check the cache first and only run inference on the uncached subset,
+        // splicing cached vectors back into their original positions.
+        let keys: Vec<u64> = texts
+            .iter()
+            .map(|t| cache::content_key(&self.model_name, t))
+            .collect();
+
+        let mut result: Vec<Option<Vec<f32>>> = vec![None; texts.len()];
+        let mut miss_indices = Vec::new();
+        let mut miss_texts = Vec::new();
+
+        for (i, key) in keys.iter().enumerate() {
+            if let Some(cached) = self.cache.get(*key) {
+                result[i] = Some(cached);
+            } else {
+                miss_indices.push(i);
+                miss_texts.push(texts[i].clone());
+            }
+        }
+
+        debug!(
+            "Embedding batch of {} texts ({} cache hits, {} misses)",
+            texts.len(),
+            texts.len() - miss_texts.len(),
+            miss_texts.len()
+        );
+
+        if !miss_texts.is_empty() {
+            let computed = self.run_inference(&miss_texts)?;
+            for (idx, vector) in miss_indices.into_iter().zip(computed.into_iter()) {
+                self.cache.insert(keys[idx], vector.clone());
+                result[idx] = Some(vector);
+            }
+        }
+
+        Ok(result.into_iter().map(|v| v.unwrap_or_default()).collect())
+    }
+
+    /// Returns the number of entries currently held in the embedding cache.
+    pub fn cache_len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Count tokens in a text without running inference, for batching decisions.
+    pub fn count_tokens(&self, text: &str) -> usize {
+        self.tokenizer
+            .encode(text, true)
+            .map(|e| e.get_ids().len())
+            .unwrap_or(0)
+    }
 
+    /// Run the ONNX session on a batch of texts with no cache involved.
+    fn run_inference(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
         // Tokenize all texts
         let encodings = self
             .tokenizer
@@ -174,6 +235,29 @@ impl EmbeddingModel {
     }
 }
 
+#[tonic::async_trait]
+impl EmbeddingProvider for EmbeddingModel {
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        self.embed_batch(texts)
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dimensions()
+    }
+
+    fn model_name(&self) -> &str {
+        self.model_name()
+    }
+
+    fn count_tokens(&self, text: &str) -> usize {
+        self.count_tokens(text)
+    }
+
+    fn cache_len(&self) -> usize {
+        self.cache_len()
+    }
+}
+
 /// Mean pooling implementation
 fn mean_pooling(
     token_embeddings: &Array2<f32>,
@@ -0,0 +1,31 @@
+use anyhow::Result;
+
+/// A backend capable of turning text into embedding vectors.
+///
+/// `EmbeddingModel` (local ONNX) and `HttpEmbeddingProvider` (an OpenAI- or
+/// Ollama-compatible HTTP endpoint) both implement this, so `embedding::embed`
+/// and `embedding::embed_batch` can run against either without the rest of
+/// the service knowing which backend is in use. Select the active provider
+/// via `ModelsConfig::provider`.
+#[tonic::async_trait]
+pub trait EmbeddingProvider: Send + Sync {
+    /// Generate embeddings for a batch of texts, in the same order as the input.
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>>;
+
+    /// Dimensionality of the vectors this provider returns.
+    fn dimensions(&self) -> usize;
+
+    /// Identifier of the underlying model, for logging and gRPC responses.
+    fn model_name(&self) -> &str;
+
+    /// Count tokens in a text without running inference, used for batching decisions.
+    /// Providers that can't tokenize locally fall back to a whitespace heuristic.
+    fn count_tokens(&self, text: &str) -> usize {
+        text.split_whitespace().count()
+    }
+
+    /// Number of entries held in this provider's embedding cache, if any.
+    fn cache_len(&self) -> usize {
+        0
+    }
+}
@@ -0,0 +1,190 @@
+use anyhow::{anyhow, Result};
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+use tracing::{debug, warn};
+
+use super::{count_tokens, embed_batch};
+
+/// A text submitted to the queue, paired with the channel used to deliver
+/// its resulting embedding back to the caller.
+struct QueueItem {
+    text: String,
+    tokens: usize,
+    respond_to: oneshot::Sender<Result<Vec<f32>>>,
+}
+
+/// Token-budget-aware batching queue for `embed_batch`.
+///
+/// Individual callers submit texts one at a time; the queue tokenizes each
+/// text up front, greedily groups submissions into micro-batches bounded by
+/// a total-token budget rather than a fixed item count, and flushes a batch
+/// as soon as the budget is hit or a short debounce window elapses with no
+/// new submissions. This avoids padding every item in a batch to the length
+/// of the single longest one.
+pub struct EmbeddingQueue {
+    sender: mpsc::UnboundedSender<QueueItem>,
+}
+
+impl EmbeddingQueue {
+    /// Spawn the background flush task and return a handle that can be
+    /// shared to submit texts incrementally.
+    pub fn new(token_budget: usize, debounce: Duration) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        tokio::spawn(Self::run(receiver, token_budget.max(1), debounce));
+        Self { sender }
+    }
+
+    /// Submit a text for embedding and await its result. Resolves once the
+    /// text's micro-batch has been flushed.
+    pub async fn submit(&self, text: String) -> Result<Vec<f32>> {
+        let tokens = count_tokens(&text);
+        let (respond_to, receiver) = oneshot::channel();
+
+        self.sender
+            .send(QueueItem {
+                text,
+                tokens,
+                respond_to,
+            })
+            .map_err(|_| anyhow!("Embedding queue has shut down"))?;
+
+        receiver
+            .await
+            .map_err(|_| anyhow!("Embedding queue dropped the response"))?
+    }
+
+    async fn run(
+        mut receiver: mpsc::UnboundedReceiver<QueueItem>,
+        token_budget: usize,
+        debounce: Duration,
+    ) {
+        let mut pending: Vec<QueueItem> = Vec::new();
+        let mut pending_tokens = 0usize;
+
+        loop {
+            tokio::select! {
+                biased;
+
+                item = receiver.recv() => {
+                    match item {
+                        Some(item) => {
+                            pending_tokens += item.tokens;
+                            pending.push(item);
+                            if pending_tokens >= token_budget {
+                                Self::flush(&mut pending, token_budget).await;
+                                pending_tokens = 0;
+                            }
+                        }
+                        None => {
+                            Self::flush(&mut pending, token_budget).await;
+                            break;
+                        }
+                    }
+                }
+                _ = tokio::time::sleep(debounce), if !pending.is_empty() => {
+                    Self::flush(&mut pending, token_budget).await;
+                    pending_tokens = 0;
+                }
+            }
+        }
+    }
+
+    /// Flush the current pending batch. Items are sorted by token count and
+    /// then re-grouped into sub-batches that each stay within `token_budget`,
+    /// so similar-length texts land in the same `embed_batch` call and the
+    /// provider never pads a short text out to a much longer one's length.
+    /// Input ordering is preserved from each caller's point of view since
+    /// every item resolves its own future regardless of batch order.
+    async fn flush(pending: &mut Vec<QueueItem>, token_budget: usize) {
+        if pending.is_empty() {
+            return;
+        }
+
+        let mut items = std::mem::take(pending);
+        items.sort_by_key(|item| item.tokens);
+
+        debug!(
+            "Flushing embedding queue: {} items, {} total tokens",
+            items.len(),
+            items.iter().map(|i| i.tokens).sum::<usize>()
+        );
+
+        let token_counts: Vec<usize> = items.iter().map(|item| item.tokens).collect();
+        let mut items = items.into_iter();
+
+        for size in Self::group_sizes(&token_counts, token_budget) {
+            let batch: Vec<QueueItem> = (&mut items).take(size).collect();
+            Self::flush_batch(batch).await;
+        }
+    }
+
+    /// Given token counts already sorted ascending, greedily group them into
+    /// runs whose summed tokens stay within `token_budget`, returning each
+    /// run's size. A single item over budget still gets its own run rather
+    /// than being dropped or split.
+    fn group_sizes(sorted_tokens: &[usize], token_budget: usize) -> Vec<usize> {
+        let mut sizes = Vec::new();
+        let mut current = 0usize;
+        let mut current_tokens = 0usize;
+
+        for &tokens in sorted_tokens {
+            if current > 0 && current_tokens + tokens > token_budget {
+                sizes.push(current);
+                current = 0;
+                current_tokens = 0;
+            }
+            current += 1;
+            current_tokens += tokens;
+        }
+
+        if current > 0 {
+            sizes.push(current);
+        }
+
+        sizes
+    }
+
+    /// Run one sub-batch through `embed_batch` and deliver each item's result.
+    async fn flush_batch(items: Vec<QueueItem>) {
+        let texts: Vec<String> = items.iter().map(|item| item.text.clone()).collect();
+
+        match embed_batch(&texts).await {
+            Ok(vectors) => {
+                for (item, vector) in items.into_iter().zip(vectors.into_iter()) {
+                    let _ = item.respond_to.send(Ok(vector));
+                }
+            }
+            Err(e) => {
+                warn!("Embedding queue batch failed: {}", e);
+                for item in items {
+                    let _ = item.respond_to.send(Err(anyhow!("{}", e)));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queue_item_tokens_tracked() {
+        // count_tokens falls back to whitespace splitting when no model is
+        // initialized, which is the case in unit tests.
+        assert_eq!(count_tokens("three word text"), 3);
+    }
+
+    #[test]
+    fn test_group_sizes_splits_on_budget() {
+        let sizes = EmbeddingQueue::group_sizes(&[1, 2, 3, 10, 11], 5);
+        assert_eq!(sizes, vec![2, 1, 1, 1]);
+        assert_eq!(sizes.iter().sum::<usize>(), 5);
+    }
+
+    #[test]
+    fn test_group_sizes_keeps_one_batch_under_budget() {
+        let sizes = EmbeddingQueue::group_sizes(&[1, 2, 3], 100);
+        assert_eq!(sizes, vec![3]);
+    }
+}
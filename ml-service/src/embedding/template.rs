@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+static FIELD: Lazy<Regex> = Lazy::new(|| Regex::new(r"\{\{\s*([a-zA-Z0-9_]+)\s*\}\}").unwrap());
+
+/// A parsed embedding prompt template, e.g. `"{{title}}\n{{skills}}\n{{content}}"`.
+///
+/// Each line is rendered independently by substituting `{{field}}` placeholders
+/// from a document's metadata (`content` addresses the document body itself);
+/// a line whose placeholders all resolve to an empty value is dropped, so
+/// sparse records don't end up with blank lines baked into their embedding input.
+#[derive(Debug, Clone)]
+pub struct DocumentTemplate {
+    lines: Vec<String>,
+}
+
+impl DocumentTemplate {
+    /// Parse and validate `template`, rejecting unbalanced or malformed
+    /// placeholders so a typo in config fails fast here instead of silently
+    /// embedding the literal `{{...}}` text at indexing time.
+    pub fn parse(template: &str) -> Result<Self> {
+        if template.matches("{{").count() != template.matches("}}").count() {
+            bail!("invalid embedding template (unbalanced braces): {:?}", template);
+        }
+
+        let stripped = FIELD.replace_all(template, "");
+        if stripped.contains("{{") || stripped.contains("}}") {
+            bail!("invalid embedding template (malformed placeholder): {:?}", template);
+        }
+
+        Ok(Self {
+            lines: template.lines().map(str::to_string).collect(),
+        })
+    }
+
+    /// Render the template against a document's `metadata` and `content`.
+    pub fn render(&self, metadata: &HashMap<String, String>, content: &str) -> String {
+        let mut rendered_lines = Vec::with_capacity(self.lines.len());
+
+        for line in &self.lines {
+            let mut any_non_empty = false;
+            let rendered = FIELD.replace_all(line, |caps: &regex::Captures| {
+                let field = &caps[1];
+                let value: &str = if field == "content" {
+                    content
+                } else {
+                    metadata.get(field).map(String::as_str).unwrap_or("")
+                };
+                if !value.is_empty() {
+                    any_non_empty = true;
+                }
+                value.to_string()
+            });
+
+            if any_non_empty || !line.contains("{{") {
+                rendered_lines.push(rendered.into_owned());
+            }
+        }
+
+        rendered_lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_renders_fields_and_content() {
+        let template = DocumentTemplate::parse("{{title}}\n{{skills}}\n{{content}}").unwrap();
+        let mut metadata = HashMap::new();
+        metadata.insert("title".to_string(), "Backend Engineer".to_string());
+        metadata.insert("skills".to_string(), "Rust, Go".to_string());
+
+        let rendered = template.render(&metadata, "Built distributed systems.");
+        assert_eq!(rendered, "Backend Engineer\nRust, Go\nBuilt distributed systems.");
+    }
+
+    #[test]
+    fn test_omits_lines_with_empty_fields() {
+        let template = DocumentTemplate::parse("{{title}}\n{{skills}}\n{{content}}").unwrap();
+        let metadata = HashMap::new();
+
+        let rendered = template.render(&metadata, "Built distributed systems.");
+        assert_eq!(rendered, "Built distributed systems.");
+    }
+
+    #[test]
+    fn test_rejects_unbalanced_braces() {
+        assert!(DocumentTemplate::parse("{{title}\n{{content}}").is_err());
+    }
+
+    #[test]
+    fn test_rejects_unknown_placeholder_syntax() {
+        assert!(DocumentTemplate::parse("{{ {{content}} }}").is_err());
+    }
+}
@@ -6,7 +6,8 @@ use tracing::{debug, error, info};
 
 use super::ml::ml_service_server::MlService;
 use super::ml::*;
-use crate::embedding::{self, get_embedding_model};
+use crate::chunking::{chunk_document, ChunkerConfig};
+use crate::embedding::{self, get_embedding_provider};
 use crate::ner::SkillExtractor;
 use crate::reranker::{self, RankDocument};
 use crate::search::{HybridSearch, SearchMode};
@@ -31,6 +32,10 @@ impl MlService for MlServiceImpl {
         let req = request.into_inner();
         debug!("Embed request for text ({} chars)", req.text.len());
 
+        if req.text.trim().is_empty() {
+            return Err(Status::invalid_argument("text must not be empty or whitespace-only"));
+        }
+
         let embedding = embedding::embed(&req.text)
             .await
             .map_err(|e| {
@@ -38,8 +43,8 @@ impl MlService for MlServiceImpl {
                 Status::internal(format!("Embedding failed: {}", e))
             })?;
 
-        let model = get_embedding_model()
-            .map(|m| m.read().model_name().to_string())
+        let model = get_embedding_provider()
+            .map(|p| p.model_name().to_string())
             .unwrap_or_default();
 
         Ok(Response::new(EmbedResponse {
@@ -56,6 +61,20 @@ impl MlService for MlServiceImpl {
         let req = request.into_inner();
         debug!("Batch embed request for {} texts", req.texts.len());
 
+        if req.texts.is_empty() {
+            let model = get_embedding_provider()
+                .map(|p| p.model_name().to_string())
+                .unwrap_or_default();
+            return Ok(Response::new(EmbedBatchResponse { embeddings: Vec::new(), model }));
+        }
+
+        if let Some(i) = req.texts.iter().position(|t| t.trim().is_empty()) {
+            return Err(Status::invalid_argument(format!(
+                "text at index {} is empty or whitespace-only",
+                i
+            )));
+        }
+
         let embeddings = embedding::embed_batch(&req.texts)
             .await
             .map_err(|e| {
@@ -63,8 +82,8 @@ impl MlService for MlServiceImpl {
                 Status::internal(format!("Batch embedding failed: {}", e))
             })?;
 
-        let model = get_embedding_model()
-            .map(|m| m.read().model_name().to_string())
+        let model = get_embedding_provider()
+            .map(|p| p.model_name().to_string())
             .unwrap_or_default();
 
         let embeddings: Vec<Embedding> = embeddings
@@ -118,6 +137,7 @@ impl MlService for MlServiceImpl {
                 id: r.id,
                 content: r.content,
                 score: r.score,
+                calibrated_score: r.calibrated_score,
                 original_rank: r.original_rank,
                 new_rank: r.new_rank,
                 metadata: r.metadata,
@@ -135,6 +155,10 @@ impl MlService for MlServiceImpl {
         let req = request.into_inner();
         let start = Instant::now();
 
+        if req.query.trim().is_empty() {
+            return Err(Status::invalid_argument("query must not be empty or whitespace-only"));
+        }
+
         debug!(
             "Search request: query='{}', collection='{}', top_k={}, hybrid={}",
             &req.query[..req.query.len().min(50)],
@@ -145,13 +169,58 @@ impl MlService for MlServiceImpl {
 
         let top_k = if req.top_k > 0 { req.top_k as usize } else { 10 };
 
+        // A weight/ratio of 1.0 means there's no BM25 path to fall back to, so
+        // such a request is really a pure vector search: an embedding failure
+        // there should fail the RPC instead of silently degrading to BM25-only
+        // results the caller never asked for.
         let mode = if req.use_hybrid {
-            let weight = if req.vector_weight > 0.0 {
-                req.vector_weight
+            if req.fusion_mode == "linear" {
+                let semantic_ratio = if req.semantic_ratio > 0.0 {
+                    req.semantic_ratio
+                } else {
+                    0.5
+                };
+                if semantic_ratio >= 1.0 {
+                    SearchMode::Vector
+                } else {
+                    SearchMode::Linear { semantic_ratio }
+                }
             } else {
-                0.7
-            };
-            SearchMode::Hybrid { vector_weight: weight }
+                let weight = if req.vector_weight > 0.0 {
+                    req.vector_weight
+                } else {
+                    0.7
+                };
+                if weight >= 1.0 {
+                    SearchMode::Vector
+                } else {
+                    // Only treat keyword_confidence as "set" when positive, same
+                    // convention as vector_weight/semantic_ratio above — 0.0 means
+                    // the caller didn't configure lazy embedding.
+                    let keyword_confidence = if req.keyword_confidence > 0.0 {
+                        Some(req.keyword_confidence)
+                    } else {
+                        None
+                    };
+                    // A caller that already knows its collection's vector score
+                    // distribution (e.g. from an offline calibration pass) can supply
+                    // it directly; otherwise `HybridSearch` falls back to its own
+                    // rolling-window estimate. std_dev <= 0.0 means "not supplied".
+                    let distribution_shift = if req.score_distribution_std_dev > 0.0 {
+                        Some(crate::search::DistributionShift {
+                            mean: req.score_distribution_mean,
+                            std_dev: req.score_distribution_std_dev,
+                        })
+                    } else {
+                        None
+                    };
+                    SearchMode::Hybrid {
+                        vector_weight: weight,
+                        keyword_confidence,
+                        distribution_shift,
+                    }
+                }
+            }
         } else {
             SearchMode::Vector
         };
@@ -186,6 +255,13 @@ impl MlService for MlServiceImpl {
 
         // Apply reranking if requested
         if req.use_reranking && !results.is_empty() {
+            // Preserve each result's pre-rerank breakdown (vector/BM25/fusion) so it
+            // can be merged with the rerank stage below instead of being discarded.
+            let pre_rerank_details: HashMap<String, crate::search::ScoreDetails> = results
+                .iter()
+                .filter_map(|r| r.score_details.clone().map(|d| (r.id.clone(), d)))
+                .collect();
+
             let docs: Vec<RankDocument> = results
                 .iter()
                 .map(|r| RankDocument {
@@ -205,26 +281,74 @@ impl MlService for MlServiceImpl {
 
             results = ranked
                 .into_iter()
-                .map(|r| crate::search::SearchResult {
-                    id: r.id,
-                    content: r.content,
-                    score: r.score,
-                    metadata: r.metadata,
-                    source: crate::search::SearchSource::Hybrid,
+                .map(|r| {
+                    let mut score_details = pre_rerank_details.get(&r.id).cloned().unwrap_or_default();
+                    score_details.rerank_score = Some(r.score);
+                    score_details.rerank_original_rank = Some(r.original_rank);
+                    score_details.rerank_new_rank = Some(r.new_rank);
+
+                    crate::search::SearchResult {
+                        id: r.id,
+                        content: r.content,
+                        score: r.calibrated_score,
+                        metadata: r.metadata,
+                        source: crate::search::SearchSource::Hybrid,
+                        score_details: Some(score_details),
+                    }
                 })
                 .collect();
         } else {
             results.truncate(top_k);
         }
 
+        // How many of the returned hits actually came from the vector leg. In
+        // hybrid mode a result's `source` is always `Hybrid` regardless of which
+        // leg(s) fed it, so this has to look at `score_details.vector_score`
+        // rather than `source` — computed before the breakdown is dropped below.
+        // In vector mode every hit came from the vector leg, including after
+        // reranking, which gives results a `Hybrid` source.
+        let vector_mode = matches!(mode, SearchMode::Vector);
+        let semantic_hit_count = results
+            .iter()
+            .filter(|r| {
+                vector_mode
+                    || match &r.score_details {
+                        Some(details) => details.vector_score.is_some(),
+                        None => r.source == crate::search::SearchSource::Vector,
+                    }
+            })
+            .count() as i32;
+
+        // Drop the per-stage breakdown before returning unless the caller asked for
+        // it, keeping the default hot path allocation-light.
+        if !req.with_score_details {
+            for r in &mut results {
+                r.score_details = None;
+            }
+        }
+
         let search_mode = match mode {
             SearchMode::Vector => "vector",
             SearchMode::BM25 => "bm25",
             SearchMode::Hybrid { .. } => "hybrid",
+            SearchMode::Linear { .. } => "linear",
         };
 
         let latency_ms = start.elapsed().as_millis() as i64;
 
+        // Hybrid/linear search already degrades to BM25-only internally on an
+        // embedding failure (see `HybridSearch::unwrap_vector_arm`) rather than
+        // erroring; surface that here so callers can tell a "hybrid" response
+        // apart from one that silently lost its vector arm.
+        let degraded = results
+            .iter()
+            .any(|r| r.metadata.get("degraded").map(String::as_str) == Some("true"));
+        // Set by `HybridSearch::hybrid_search` when `keyword_confidence` let it
+        // skip the query embedding and vector leg entirely.
+        let embedding_skipped = results
+            .iter()
+            .any(|r| r.metadata.get("embedding_skipped").map(String::as_str) == Some("true"));
+
         let results: Vec<SearchResult> = results
             .into_iter()
             .map(|r| SearchResult {
@@ -233,6 +357,18 @@ impl MlService for MlServiceImpl {
                 score: r.score,
                 metadata: r.metadata,
                 source: r.source.to_string(),
+                score_details: r.score_details.map(|d| ScoreDetails {
+                    vector_score: d.vector_score,
+                    vector_rank: d.vector_rank.map(|r| r as i32),
+                    bm25_score: d.bm25_score,
+                    bm25_rank: d.bm25_rank.map(|r| r as i32),
+                    fusion_method: d.fusion_method.to_string(),
+                    fusion_weight: d.fusion_weight,
+                    fused_score: d.fused_score,
+                    rerank_score: d.rerank_score,
+                    rerank_original_rank: d.rerank_original_rank,
+                    rerank_new_rank: d.rerank_new_rank,
+                }),
             })
             .collect();
 
@@ -246,6 +382,71 @@ impl MlService for MlServiceImpl {
             results,
             search_mode: search_mode.to_string(),
             latency_ms,
+            degraded,
+            embedding_skipped,
+            semantic_hit_count,
+        }))
+    }
+
+    async fn federated_search(
+        &self,
+        request: Request<FederatedSearchRequest>,
+    ) -> Result<Response<FederatedSearchResponse>, Status> {
+        let req = request.into_inner();
+        let start = Instant::now();
+
+        debug!(
+            "Federated search request across {} collection queries",
+            req.queries.len()
+        );
+
+        let top_k = if req.top_k > 0 { req.top_k as usize } else { 10 };
+
+        let queries: Vec<crate::search::FederatedQuery> = req
+            .queries
+            .into_iter()
+            .map(|q| crate::search::FederatedQuery {
+                collection: q.collection,
+                query: q.query,
+                filters: if q.filters.is_empty() { None } else { Some(q.filters) },
+                weight: if q.weight > 0.0 { q.weight } else { 1.0 },
+            })
+            .collect();
+
+        let results = self
+            .hybrid_search
+            .search_federated_queries(&queries, top_k, SearchMode::default())
+            .await
+            .map_err(|e| {
+                error!("Federated search failed: {}", e);
+                Status::internal(format!("Federated search failed: {}", e))
+            })?;
+
+        let latency_ms = start.elapsed().as_millis() as i64;
+
+        let results: Vec<FederatedSearchResult> = results
+            .into_iter()
+            .map(|mut r| {
+                let collection = r.metadata.remove("source_collection").unwrap_or_default();
+                FederatedSearchResult {
+                    id: r.id,
+                    content: r.content,
+                    score: r.score,
+                    collection,
+                    metadata: r.metadata,
+                }
+            })
+            .collect();
+
+        debug!(
+            "Federated search completed in {}ms, returned {} results",
+            latency_ms,
+            results.len()
+        );
+
+        Ok(Response::new(FederatedSearchResponse {
+            results,
+            latency_ms,
         }))
     }
 
@@ -256,7 +457,17 @@ impl MlService for MlServiceImpl {
         let req = request.into_inner();
         debug!("Extract skills request ({} chars)", req.text.len());
 
-        let skills = self.skill_extractor.extract(&req.text, req.include_soft_skills);
+        let max_edit_distance = if req.max_edit_distance > 0 {
+            req.max_edit_distance as usize
+        } else {
+            2
+        };
+        let skills = self.skill_extractor.extract_with_options(
+            &req.text,
+            req.include_soft_skills,
+            req.fuzzy_match,
+            max_edit_distance,
+        );
 
         Ok(Response::new(ExtractSkillsResponse {
             technical_skills: skills.technical_skills,
@@ -278,38 +489,78 @@ impl MlService for MlServiceImpl {
             req.collection
         );
 
+        // Reject a dimension-mismatched caller-supplied embedding up front,
+        // naming the offending document, instead of silently indexing a vector
+        // that doesn't match the collection and corrupting every search against it.
+        let expected_dims = embedding::get_dimensions();
+        for doc in &req.documents {
+            if !doc.embedding.is_empty() && doc.embedding.len() != expected_dims {
+                return Err(Status::invalid_argument(format!(
+                    "document '{}' supplied an embedding of dimension {}, expected {}",
+                    doc.id,
+                    doc.embedding.len(),
+                    expected_dims
+                )));
+            }
+        }
+
         let mut indexed_count = 0;
         let mut failed_count = 0;
         let mut failed_ids = Vec::new();
 
         // Process documents
         let mut docs_with_embeddings = Vec::new();
+        let chunker_config = ChunkerConfig::default();
 
         for doc in req.documents {
-            // Use provided embedding or generate one
-            let embedding = if !doc.embedding.is_empty() {
-                doc.embedding
-            } else {
-                match embedding::embed(&doc.content).await {
-                    Ok(emb) => emb,
-                    Err(e) => {
-                        error!("Failed to embed document {}: {}", doc.id, e);
-                        failed_count += 1;
-                        failed_ids.push(doc.id);
-                        continue;
+            // Render structured metadata (title, skills, ...) alongside the free
+            // text through the configured prompt template before chunking, so
+            // users can tune exactly what represents a record semantically.
+            let rendered = embedding::render_document(&doc.metadata, &doc.content);
+
+            // Split long documents into token-bounded chunks so nothing is
+            // silently truncated by the embedding model's token limit. Short
+            // documents come back as a single chunk spanning the whole text.
+            let chunks = chunk_document(&doc.id, &rendered, &chunker_config);
+            let single_chunk = chunks.len() == 1;
+
+            for chunk in chunks {
+                // A caller-provided embedding only applies when the document
+                // fit in a single chunk; anything split further needs its own.
+                let embedding = if single_chunk && !doc.embedding.is_empty() {
+                    doc.embedding.clone()
+                } else {
+                    match embedding::embed(&chunk.text).await {
+                        Ok(emb) => emb,
+                        Err(e) => {
+                            error!(
+                                "Failed to embed chunk {} of document {}: {}",
+                                chunk.ordinal, doc.id, e
+                            );
+                            failed_count += 1;
+                            failed_ids.push(doc.id.clone());
+                            continue;
+                        }
                     }
+                };
+
+                let chunk_id = format!("{}::chunk::{}", doc.id, chunk.ordinal);
+
+                // Add to BM25 index if requested
+                if req.update_bm25 {
+                    self.hybrid_search.add_to_bm25(&req.collection, &chunk_id, &chunk.text);
                 }
-            };
 
-            // Add to BM25 index if requested
-            if req.update_bm25 {
-                self.hybrid_search.add_to_bm25(&req.collection, &doc.id, &doc.content);
+                // Prepare for vector index, carrying chunk provenance so a
+                // search hit can be mapped back to a location in the source text.
+                let mut metadata = doc.metadata.clone();
+                metadata.insert("content".to_string(), chunk.text);
+                metadata.insert("document_id".to_string(), chunk.document_id);
+                metadata.insert("chunk_ordinal".to_string(), chunk.ordinal.to_string());
+                metadata.insert("chunk_start".to_string(), chunk.start_byte.to_string());
+                metadata.insert("chunk_end".to_string(), chunk.end_byte.to_string());
+                docs_with_embeddings.push((chunk_id, embedding, metadata));
             }
-
-            // Prepare for vector index
-            let mut metadata = doc.metadata;
-            metadata.insert("content".to_string(), doc.content);
-            docs_with_embeddings.push((doc.id, embedding, metadata));
         }
 
         // Batch index to Qdrant
@@ -356,7 +607,7 @@ impl MlService for MlServiceImpl {
         let mut components = HashMap::new();
 
         // Check embedding model
-        let embedding_status = if get_embedding_model().is_some() {
+        let embedding_status = if get_embedding_provider().is_some() {
             "healthy"
         } else {
             "not_initialized"
@@ -84,7 +84,12 @@ async fn main() -> Result<()> {
     }
 
     // Create hybrid search
-    let hybrid_search = HybridSearch::new(qdrant_client, config.search.rrf_k);
+    let hybrid_search = HybridSearch::with_min_scores(
+        qdrant_client,
+        config.search.rrf_k,
+        config.search.rag_min_score_vector,
+        config.search.rag_min_score_text,
+    );
 
     // Create gRPC service
     let ml_service = MlServiceImpl::new(hybrid_search);
@@ -1,9 +1,11 @@
 use anyhow::Result;
 use once_cell::sync::Lazy;
 use regex::Regex;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use tracing::debug;
 
+use crate::analyzer::Analyzer;
+
 /// Extracted skills categorized by type
 #[derive(Debug, Clone, Default)]
 pub struct ExtractedSkills {
@@ -22,6 +24,10 @@ pub struct SkillExtractor {
     tools: HashSet<String>,
     soft_skills: HashSet<String>,
     technical_skills: HashSet<String>,
+    /// Stopword removal (and, if enabled, stemming) applied to tokenized
+    /// text on top of this module's own symbol-preserving tokenizer, shared
+    /// with `BM25Index` so the two modules treat text consistently.
+    analyzer: Analyzer,
 }
 
 // Common programming languages
@@ -140,6 +146,120 @@ static TECHNICAL_SKILLS: Lazy<HashSet<String>> = Lazy::new(|| {
     .collect()
 });
 
+// Alias/synonym table mapping common variants to one canonical skill name,
+// so e.g. "golang" and "go" are reported as the same skill.
+static ALIASES: Lazy<HashMap<String, String>> = Lazy::new(|| {
+    [
+        ("golang", "go"),
+        ("reactjs", "react"),
+        ("react.js", "react"),
+        ("vuejs", "vue"),
+        ("vue.js", "vue"),
+        ("angularjs", "angular"),
+        ("nextjs", "next.js"),
+        ("nuxtjs", "nuxt"),
+        ("expressjs", "express"),
+        ("nest.js", "nestjs"),
+        ("springboot", "spring boot"),
+        ("dotnet", ".net"),
+        ("k8s", "kubernetes"),
+        ("postgres", "postgresql"),
+        ("sklearn", "scikit-learn"),
+        ("vscode", "vs code"),
+        ("ab testing", "a/b testing"),
+        ("cicd", "ci/cd"),
+        ("ml", "machine learning"),
+        ("cv", "computer vision"),
+        ("nlp", "natural language processing"),
+        ("decision making", "decision-making"),
+        ("problem solving", "problem-solving"),
+    ]
+    .iter()
+    .map(|(alias, canonical)| (alias.to_string(), canonical.to_string()))
+    .collect()
+});
+
+/// Minimum token length eligible for fuzzy matching at all.
+const FUZZY_MIN_LEN: usize = 5;
+/// Token length at which the allowed edit distance budget grows from 1 to 2.
+const FUZZY_WIDE_BUDGET_LEN: usize = 8;
+
+/// Bounded Damerau-Levenshtein distance between `a` and `b`, bailing out
+/// early (returning `None`) once the running cost in every cell of the
+/// current row exceeds `max_distance` — so a token can be checked against a
+/// large skill set without paying for a full edit-distance matrix each time.
+fn bounded_edit_distance(a: &str, b: &str, max_distance: usize) -> Option<usize> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    if a.len().abs_diff(b.len()) > max_distance {
+        return None;
+    }
+
+    let mut prev_row: Vec<usize> = (0..=b.len()).collect();
+    let mut prev_prev_row: Vec<usize> = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        let mut cur_row = vec![0usize; b.len() + 1];
+        cur_row[0] = i;
+        let mut row_min = cur_row[0];
+
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            let mut value = (cur_row[j - 1] + 1)
+                .min(prev_row[j] + 1)
+                .min(prev_row[j - 1] + cost);
+
+            // Transposition (Damerau): swap of the last two characters.
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                value = value.min(prev_prev_row[j - 2] + 1);
+            }
+
+            cur_row[j] = value;
+            row_min = row_min.min(value);
+        }
+
+        if row_min > max_distance {
+            return None;
+        }
+
+        prev_prev_row = prev_row;
+        prev_row = cur_row;
+    }
+
+    let distance = prev_row[b.len()];
+    (distance <= max_distance).then_some(distance)
+}
+
+/// The edit-distance budget a token of this length is allowed: too short to
+/// fuzzy-match at all, then 1, then 2 for longer tokens where a typo is
+/// proportionally less likely to change the word's meaning.
+fn fuzzy_budget(token_len: usize) -> usize {
+    if token_len >= FUZZY_WIDE_BUDGET_LEN {
+        2
+    } else if token_len >= FUZZY_MIN_LEN {
+        1
+    } else {
+        0
+    }
+}
+
+/// A skill set entry bucketed by length, so fuzzy matching only compares a
+/// token against candidates whose length could plausibly be within budget.
+fn bucket_by_length(skill_set: &HashSet<String>) -> HashMap<usize, Vec<&String>> {
+    let mut buckets: HashMap<usize, Vec<&String>> = HashMap::new();
+    for skill in skill_set {
+        buckets.entry(skill.chars().count()).or_default().push(skill);
+    }
+    buckets
+}
+
+/// Map a matched skill string to its canonical form via `ALIASES`, or return
+/// it unchanged if it has no alias entry.
+fn canonicalize(skill: &str) -> String {
+    ALIASES.get(skill).cloned().unwrap_or_else(|| skill.to_string())
+}
+
 impl Default for SkillExtractor {
     fn default() -> Self {
         Self::new()
@@ -148,39 +268,99 @@ impl Default for SkillExtractor {
 
 impl SkillExtractor {
     pub fn new() -> Self {
+        Self::with_analyzer(Analyzer::new())
+    }
+
+    /// Build an extractor with a custom `Analyzer`, e.g. with stemming
+    /// enabled. Stemming is off in the default `Analyzer`, since the skill
+    /// sets above are literal strings that a stem wouldn't match.
+    pub fn with_analyzer(analyzer: Analyzer) -> Self {
         Self {
             programming_languages: PROGRAMMING_LANGUAGES.clone(),
             frameworks: FRAMEWORKS.clone(),
             tools: TOOLS.clone(),
             soft_skills: SOFT_SKILLS.clone(),
             technical_skills: TECHNICAL_SKILLS.clone(),
+            analyzer,
         }
     }
 
     /// Extract skills from text
     pub fn extract(&self, text: &str, include_soft_skills: bool) -> ExtractedSkills {
+        self.extract_with_options(text, include_soft_skills, false, 0)
+    }
+
+    /// Extract skills from text, optionally tolerating typos.
+    ///
+    /// When `fuzzy` is true, a token that doesn't match a skill set entry
+    /// exactly is also checked within a bounded edit distance (capped at
+    /// `max_edit_distance`, and further capped by the token's own length —
+    /// see `fuzzy_budget`). Every matched skill, exact or fuzzy, is reported
+    /// under its canonical name via the `ALIASES` table.
+    pub fn extract_with_options(
+        &self,
+        text: &str,
+        include_soft_skills: bool,
+        fuzzy: bool,
+        max_edit_distance: usize,
+    ) -> ExtractedSkills {
         debug!("Extracting skills from text ({} chars)", text.len());
 
         let text_lower = text.to_lowercase();
-        let words = self.tokenize(&text_lower);
+        // `tokenize` keeps symbols like `+`/`#`/`.` that skill names rely on
+        // (c++, c#, node.js); the analyzer only strips stopwords on top of
+        // that, it doesn't re-tokenize from scratch. A sentence-final period
+        // ("... and Go.") is trimmed so the word still matches its skill entry.
+        let words = self.analyzer.analyze_tokens(
+            self.tokenize(&text_lower)
+                .into_iter()
+                .map(|w| w.trim_end_matches('.').to_string())
+                .filter(|w| !w.is_empty())
+                .collect(),
+        );
 
         let mut result = ExtractedSkills::default();
 
         // Extract programming languages
-        result.languages = self.extract_matches(&words, &text_lower, &self.programming_languages);
+        result.languages = self.extract_matches(
+            &words,
+            &text_lower,
+            &self.programming_languages,
+            fuzzy,
+            max_edit_distance,
+        );
 
         // Extract frameworks
-        result.frameworks = self.extract_matches(&words, &text_lower, &self.frameworks);
+        result.frameworks = self.extract_matches(
+            &words,
+            &text_lower,
+            &self.frameworks,
+            fuzzy,
+            max_edit_distance,
+        );
 
         // Extract tools
-        result.tools = self.extract_matches(&words, &text_lower, &self.tools);
+        result.tools =
+            self.extract_matches(&words, &text_lower, &self.tools, fuzzy, max_edit_distance);
 
         // Extract technical skills
-        result.technical_skills = self.extract_matches(&words, &text_lower, &self.technical_skills);
+        result.technical_skills = self.extract_matches(
+            &words,
+            &text_lower,
+            &self.technical_skills,
+            fuzzy,
+            max_edit_distance,
+        );
 
         // Extract soft skills if requested
         if include_soft_skills {
-            result.soft_skills = self.extract_matches(&words, &text_lower, &self.soft_skills);
+            result.soft_skills = self.extract_matches(
+                &words,
+                &text_lower,
+                &self.soft_skills,
+                fuzzy,
+                max_edit_distance,
+            );
         }
 
         debug!(
@@ -211,6 +391,8 @@ impl SkillExtractor {
         words: &[String],
         text: &str,
         skill_set: &HashSet<String>,
+        fuzzy: bool,
+        max_edit_distance: usize,
     ) -> Vec<String> {
         let mut found = HashSet::new();
 
@@ -230,8 +412,39 @@ impl SkillExtractor {
             }
         }
 
-        let mut result: Vec<String> = found.into_iter().collect();
+        // Typo-tolerant fallback: for tokens that didn't match exactly, check
+        // within a bounded edit distance against same-length-ish skill set
+        // entries rather than comparing against every candidate.
+        if fuzzy {
+            let buckets = bucket_by_length(skill_set);
+            for word in words {
+                let budget = fuzzy_budget(word.chars().count()).min(max_edit_distance);
+                if budget == 0 || skill_set.contains(word) {
+                    continue;
+                }
+
+                let word_len = word.chars().count() as isize;
+                for len_delta in -(budget as isize)..=(budget as isize) {
+                    let candidate_len = word_len + len_delta;
+                    if candidate_len < 0 {
+                        continue;
+                    }
+                    let Some(candidates) = buckets.get(&(candidate_len as usize)) else {
+                        continue;
+                    };
+                    for candidate in candidates {
+                        if bounded_edit_distance(word, candidate, budget).is_some() {
+                            found.insert((*candidate).clone());
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut result: Vec<String> = found.into_iter().map(|skill| canonicalize(&skill)).collect();
         result.sort();
+        result.dedup();
         result
     }
 }
@@ -294,4 +507,76 @@ mod tests {
 
         assert!(skills.soft_skills.is_empty());
     }
+
+    #[test]
+    fn test_fuzzy_matching_catches_misspellings() {
+        let extractor = SkillExtractor::new();
+        let text = "Proficient in Tensorlfow and Postgre for backend work.";
+
+        let exact = extractor.extract(text, false);
+        assert!(!exact.frameworks.contains(&"tensorflow".to_string()));
+
+        let fuzzy = extractor.extract_with_options(text, false, true, 2);
+        assert!(fuzzy.frameworks.contains(&"tensorflow".to_string()));
+        assert!(fuzzy.tools.contains(&"postgresql".to_string()));
+    }
+
+    #[test]
+    fn test_fuzzy_matching_disabled_by_default() {
+        let extractor = SkillExtractor::new();
+        let text = "Worked with Tensorlfow models.";
+        let skills = extractor.extract(text, false);
+        assert!(!skills.frameworks.contains(&"tensorflow".to_string()));
+    }
+
+    #[test]
+    fn test_alias_collapses_to_canonical_name() {
+        let extractor = SkillExtractor::new();
+        let text = "Years of experience with Golang and K8s.";
+        let skills = extractor.extract(text, false);
+
+        assert!(skills.languages.contains(&"go".to_string()));
+        assert!(!skills.languages.contains(&"golang".to_string()));
+        assert!(skills.tools.contains(&"kubernetes".to_string()));
+        assert!(!skills.tools.contains(&"k8s".to_string()));
+    }
+
+    #[test]
+    fn test_bounded_edit_distance_respects_budget() {
+        assert_eq!(bounded_edit_distance("kitten", "sitten", 1), Some(1));
+        assert_eq!(bounded_edit_distance("kitten", "sitting", 2), None);
+    }
+
+    #[test]
+    fn test_fuzzy_budget_scales_with_token_length() {
+        assert_eq!(fuzzy_budget(3), 0);
+        assert_eq!(fuzzy_budget(5), 1);
+        assert_eq!(fuzzy_budget(8), 2);
+    }
+
+    #[test]
+    fn test_stopword_removal_does_not_affect_skill_extraction() {
+        // Stopwords like "in"/"and"/"with" never overlap with skill-set
+        // entries, so filtering them out shouldn't change what gets matched.
+        let extractor = SkillExtractor::new();
+        let text = "I am proficient in Python, JavaScript, and Rust. I also know some Go.";
+        let skills = extractor.extract(text, false);
+
+        assert!(skills.languages.contains(&"python".to_string()));
+        assert!(skills.languages.contains(&"rust".to_string()));
+        assert!(skills.languages.contains(&"go".to_string()));
+    }
+
+    #[test]
+    fn test_with_analyzer_accepts_custom_analyzer() {
+        // Stemming is opt-in and off by default since it can misalign exact
+        // skill-set literals (e.g. "postgresql" has no plain suffix to strip),
+        // but extraction should still work normally with it enabled.
+        let extractor = SkillExtractor::with_analyzer(Analyzer::with_stemming(true));
+        let text = "Built services in Python and deployed them with Docker.";
+        let skills = extractor.extract(text, false);
+
+        assert!(skills.languages.contains(&"python".to_string()));
+        assert!(skills.tools.contains(&"docker".to_string()));
+    }
 }
@@ -50,7 +50,12 @@ pub struct RankDocument {
 pub struct RankedDocument {
     pub id: String,
     pub content: String,
+    /// Raw cross-encoder logit, model-specific in scale.
     pub score: f32,
+    /// `score` run through `sigmoid((score - mean) / sigma)` using this
+    /// model's calibration parameters, giving a bounded `[0, 1]` relevance
+    /// that's stable across `reranker_model` swaps and usable as a cutoff.
+    pub calibrated_score: f32,
     pub original_rank: i32,
     pub new_rank: i32,
     pub metadata: std::collections::HashMap<String, String>,
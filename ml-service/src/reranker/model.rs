@@ -15,6 +15,8 @@ pub struct Reranker {
     session: Session,
     tokenizer: Tokenizer,
     model_name: String,
+    calibration_mean: f32,
+    calibration_sigma: f32,
 }
 
 impl Reranker {
@@ -42,6 +44,8 @@ impl Reranker {
             session,
             tokenizer,
             model_name: config.reranker_model.clone(),
+            calibration_mean: config.reranker_calibration_mean,
+            calibration_sigma: config.reranker_calibration_sigma,
         })
     }
 
@@ -49,6 +53,13 @@ impl Reranker {
         &self.model_name
     }
 
+    /// Map a raw cross-encoder logit to a bounded `[0, 1]` relevance via
+    /// `sigmoid((logit - mean) / sigma)`, using this model's calibration
+    /// parameters (0/1 by default, i.e. a plain sigmoid).
+    fn calibrate(&self, logit: f32) -> f32 {
+        calibrate_score(logit, self.calibration_mean, self.calibration_sigma)
+    }
+
     /// Rerank documents by computing cross-encoder scores with the query
     pub fn rerank(
         &self,
@@ -156,6 +167,7 @@ impl Reranker {
                     id: doc.id.clone(),
                     content: doc.content.clone(),
                     score,
+                    calibrated_score: self.calibrate(score),
                     original_rank: original_idx as i32,
                     new_rank: new_rank as i32,
                     metadata: doc.metadata.clone(),
@@ -169,6 +181,14 @@ impl Reranker {
     }
 }
 
+/// `sigmoid((logit - mean) / sigma)`. Guards against a zero/degenerate `sigma`
+/// by falling back to 1.0 rather than dividing by zero.
+fn calibrate_score(logit: f32, mean: f32, sigma: f32) -> f32 {
+    let sigma = if sigma.abs() > f32::EPSILON { sigma } else { 1.0 };
+    let z = (logit - mean) / sigma;
+    1.0 / (1.0 + (-z).exp())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -179,7 +199,37 @@ mod tests {
             embedding_model: "test".to_string(),
             reranker_model: "nonexistent".to_string(),
             models_dir: "/nonexistent".to_string(),
+            embedding_cache_capacity: 0,
+            embedding_cache_path: None,
+            embedding_queue_token_budget: 16_000,
+            embedding_queue_debounce_ms: 10,
+            provider: "onnx".to_string(),
+            embedding_endpoint: None,
+            embedding_api_key: None,
+            embedding_dimensions: None,
+            embedding_template: "{{content}}".to_string(),
+            reranker_calibration_mean: 0.0,
+            reranker_calibration_sigma: 1.0,
         };
         assert!(Reranker::new(&config).is_err());
     }
+
+    #[test]
+    fn test_calibrate_score_default_is_plain_sigmoid() {
+        let calibrated = calibrate_score(0.0, 0.0, 1.0);
+        assert!((calibrated - 0.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_calibrate_score_is_bounded() {
+        assert!(calibrate_score(100.0, 0.0, 1.0) <= 1.0);
+        assert!(calibrate_score(-100.0, 0.0, 1.0) >= 0.0);
+    }
+
+    #[test]
+    fn test_calibrate_score_guards_zero_sigma() {
+        // A degenerate sigma of 0.0 must not divide by zero or produce NaN.
+        let calibrated = calibrate_score(5.0, 0.0, 0.0);
+        assert!(calibrated.is_finite());
+    }
 }
@@ -1,16 +1,35 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use parking_lot::RwLock;
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::path::Path;
 use tracing::debug;
 
-/// Simple BM25 index implementation for hybrid search
+use super::query::{parse_query, QueryNode};
+use crate::analyzer::Analyzer;
+
+type DocId = u32;
+
+/// BM25 index implementation for hybrid search, backed by an inverted index
+/// (posting lists per term) so a query only touches documents that actually
+/// contain one of its terms instead of scanning the whole corpus.
 pub struct BM25Index {
-    /// Document store: id -> (content, tokens)
-    documents: RwLock<HashMap<String, (String, Vec<String>)>>,
-    /// Document frequency for each term
-    doc_freq: RwLock<HashMap<String, usize>>,
-    /// Average document length
+    /// Document store: DocId -> (external id, content, doc length in tokens)
+    documents: RwLock<HashMap<DocId, (String, String, u32)>>,
+    /// External id -> internal DocId, for lookups and re-indexing.
+    id_to_doc: RwLock<HashMap<String, DocId>>,
+    /// Posting lists: term -> [(DocId, term frequency in that document)].
+    postings: RwLock<HashMap<String, Vec<(DocId, u32)>>>,
+    /// Per-document token sequence in original order, kept only to verify
+    /// phrase adjacency in `search_query` — scoring itself never needs it.
+    doc_tokens: RwLock<HashMap<DocId, Vec<String>>>,
+    /// Average document length across the corpus.
     avg_doc_len: RwLock<f32>,
+    next_doc_id: RwLock<DocId>,
+    /// Text-analysis pipeline (stopwords, optional stemming) applied to both
+    /// indexed documents and incoming queries so their terms line up.
+    analyzer: Analyzer,
     /// BM25 parameters
     k1: f32,
     b: f32,
@@ -24,54 +43,84 @@ impl Default for BM25Index {
 
 impl BM25Index {
     pub fn new() -> Self {
+        Self::with_analyzer(Analyzer::new())
+    }
+
+    pub fn with_params(k1: f32, b: f32) -> Self {
         Self {
             documents: RwLock::new(HashMap::new()),
-            doc_freq: RwLock::new(HashMap::new()),
+            id_to_doc: RwLock::new(HashMap::new()),
+            postings: RwLock::new(HashMap::new()),
+            doc_tokens: RwLock::new(HashMap::new()),
             avg_doc_len: RwLock::new(0.0),
-            k1: 1.5,
-            b: 0.75,
+            next_doc_id: RwLock::new(0),
+            analyzer: Analyzer::new(),
+            k1,
+            b,
         }
     }
 
-    pub fn with_params(k1: f32, b: f32) -> Self {
+    /// Build an index with a custom `Analyzer` (e.g. with stemming enabled),
+    /// applied identically to indexed documents and incoming queries so
+    /// their terms line up.
+    pub fn with_analyzer(analyzer: Analyzer) -> Self {
         Self {
             documents: RwLock::new(HashMap::new()),
-            doc_freq: RwLock::new(HashMap::new()),
+            id_to_doc: RwLock::new(HashMap::new()),
+            postings: RwLock::new(HashMap::new()),
+            doc_tokens: RwLock::new(HashMap::new()),
             avg_doc_len: RwLock::new(0.0),
-            k1,
-            b,
+            next_doc_id: RwLock::new(0),
+            analyzer,
+            k1: 1.5,
+            b: 0.75,
         }
     }
 
-    /// Tokenize text into lowercase terms
+    /// Raw tokenization (lowercase, split on non-alphanumeric boundaries) with
+    /// no stopword removal or stemming — used where callers need the plain
+    /// token boundaries themselves, independent of this index's `Analyzer`.
     fn tokenize(text: &str) -> Vec<String> {
-        text.to_lowercase()
-            .split(|c: char| !c.is_alphanumeric())
-            .filter(|s| !s.is_empty() && s.len() > 1)
-            .map(|s| s.to_string())
-            .collect()
+        crate::analyzer::tokenize_raw(text)
     }
 
-    /// Add a document to the index
+    /// Add a document to the index, tokenizing and counting term frequencies
+    /// once up front and appending to each term's posting list.
     pub fn add_document(&self, id: &str, content: &str) {
-        let tokens = Self::tokenize(content);
+        // Re-indexing an existing id would leave its old postings dangling,
+        // so drop it first.
+        if self.id_to_doc.read().contains_key(id) {
+            self.remove_document(id);
+        }
 
-        // Update document frequency for unique terms
-        let unique_terms: std::collections::HashSet<_> = tokens.iter().collect();
-        {
-            let mut doc_freq = self.doc_freq.write();
-            for term in unique_terms {
-                *doc_freq.entry(term.clone()).or_insert(0) += 1;
-            }
+        let tokens = self.analyzer.analyze(content);
+        let doc_len = tokens.len() as u32;
+
+        let doc_id = {
+            let mut next = self.next_doc_id.write();
+            let doc_id = *next;
+            *next += 1;
+            doc_id
+        };
+
+        let mut term_freq: HashMap<String, u32> = HashMap::new();
+        for token in &tokens {
+            *term_freq.entry(token.clone()).or_insert(0) += 1;
         }
 
-        // Store document
         {
-            let mut docs = self.documents.write();
-            docs.insert(id.to_string(), (content.to_string(), tokens));
+            let mut postings = self.postings.write();
+            for (term, tf) in term_freq {
+                postings.entry(term).or_default().push((doc_id, tf));
+            }
         }
 
-        // Update average document length
+        self.documents
+            .write()
+            .insert(doc_id, (id.to_string(), content.to_string(), doc_len));
+        self.id_to_doc.write().insert(id.to_string(), doc_id);
+        self.doc_tokens.write().insert(doc_id, tokens);
+
         self.update_avg_doc_len();
     }
 
@@ -82,6 +131,27 @@ impl BM25Index {
         }
     }
 
+    /// Remove a document from the index, pruning its entries out of every
+    /// posting list it appeared in.
+    pub fn remove_document(&self, id: &str) {
+        let doc_id = match self.id_to_doc.write().remove(id) {
+            Some(doc_id) => doc_id,
+            None => return,
+        };
+
+        self.documents.write().remove(&doc_id);
+        self.doc_tokens.write().remove(&doc_id);
+
+        let mut postings = self.postings.write();
+        postings.retain(|_, list| {
+            list.retain(|(d, _)| *d != doc_id);
+            !list.is_empty()
+        });
+        drop(postings);
+
+        self.update_avg_doc_len();
+    }
+
     /// Update average document length
     fn update_avg_doc_len(&self) {
         let docs = self.documents.read();
@@ -90,13 +160,13 @@ impl BM25Index {
             return;
         }
 
-        let total_len: usize = docs.values().map(|(_, tokens)| tokens.len()).sum();
+        let total_len: u64 = docs.values().map(|(_, _, len)| *len as u64).sum();
         *self.avg_doc_len.write() = total_len as f32 / docs.len() as f32;
     }
 
     /// Search the index and return ranked results
     pub fn search(&self, query: &str, top_k: usize) -> Vec<(String, f32)> {
-        let query_tokens = Self::tokenize(query);
+        let query_tokens = self.analyzer.analyze(query);
         if query_tokens.is_empty() {
             return vec![];
         }
@@ -104,7 +174,7 @@ impl BM25Index {
         debug!("BM25 search for {} tokens", query_tokens.len());
 
         let docs = self.documents.read();
-        let doc_freq = self.doc_freq.read();
+        let postings = self.postings.read();
         let avg_doc_len = *self.avg_doc_len.read();
         let n = docs.len() as f32;
 
@@ -112,58 +182,194 @@ impl BM25Index {
             return vec![];
         }
 
-        // Calculate BM25 scores for each document
-        let mut scores: Vec<(String, f32)> = docs
-            .iter()
-            .map(|(id, (_, doc_tokens))| {
-                let doc_len = doc_tokens.len() as f32;
-                let mut score = 0.0;
+        // Walk only the posting lists of the query's terms, accumulating a
+        // BM25 score per document instead of scanning the whole corpus.
+        let mut scores: HashMap<DocId, f32> = HashMap::new();
+        for query_term in &query_tokens {
+            let list = match postings.get(query_term) {
+                Some(list) => list,
+                None => continue,
+            };
+            let df = list.len() as f32;
+            let idf = ((n - df + 0.5) / (df + 0.5) + 1.0).ln();
+
+            for (doc_id, tf) in list {
+                let doc_len = docs
+                    .get(doc_id)
+                    .map(|(_, _, len)| *len as f32)
+                    .unwrap_or(0.0);
+                let tf = *tf as f32;
+
+                let tf_norm = (tf * (self.k1 + 1.0))
+                    / (tf + self.k1 * (1.0 - self.b + self.b * doc_len / avg_doc_len));
+
+                *scores.entry(*doc_id).or_insert(0.0) += idf * tf_norm;
+            }
+        }
 
-                // Count term frequencies in document
-                let mut term_freq: HashMap<&str, usize> = HashMap::new();
-                for token in doc_tokens {
-                    *term_freq.entry(token.as_str()).or_insert(0) += 1;
-                }
+        // Top-k via a bounded min-heap instead of sorting every scored document.
+        top_k_by_score(scores, top_k)
+            .into_iter()
+            .filter_map(|(doc_id, score)| {
+                docs.get(&doc_id).map(|(id, _, _)| (id.clone(), score))
+            })
+            .collect()
+    }
 
-                for query_term in &query_tokens {
-                    let tf = *term_freq.get(query_term.as_str()).unwrap_or(&0) as f32;
-                    let df = *doc_freq.get(query_term).unwrap_or(&0) as f32;
+    /// Run a single already-split query term through this index's `Analyzer`,
+    /// returning `None` if it's filtered out entirely (e.g. it's a stopword).
+    fn analyze_single_term(&self, term: &str) -> Option<String> {
+        self.analyzer
+            .analyze_tokens(vec![term.to_string()])
+            .into_iter()
+            .next()
+    }
 
-                    if tf > 0.0 && df > 0.0 {
-                        // IDF component
-                        let idf = ((n - df + 0.5) / (df + 0.5) + 1.0).ln();
+    /// Search using a query-tree of quoted phrases and `+required`/`-excluded`
+    /// terms (see `query::parse_query`), alongside plain keyword terms scored
+    /// with the same BM25 formula as `search`.
+    ///
+    /// A document is dropped before scoring if it's missing a `Must` term or
+    /// `Phrase`, or if it contains a `MustNot` term. Surviving documents are
+    /// scored on their `Should`/`Must`/`Phrase` terms.
+    pub fn search_query(&self, query: &str, top_k: usize) -> Vec<(String, f32)> {
+        let nodes = parse_query(query);
+        if nodes.is_empty() {
+            return vec![];
+        }
 
-                        // TF component with length normalization
-                        let tf_norm = (tf * (self.k1 + 1.0))
-                            / (tf + self.k1 * (1.0 - self.b + self.b * doc_len / avg_doc_len));
+        let docs = self.documents.read();
+        let postings = self.postings.read();
+        let doc_tokens = self.doc_tokens.read();
+        let avg_doc_len = *self.avg_doc_len.read();
+        let n = docs.len() as f32;
 
-                        score += idf * tf_norm;
+        if docs.is_empty() {
+            return vec![];
+        }
+
+        let doc_ids_for = |term: &str| -> HashSet<DocId> {
+            postings
+                .get(term)
+                .map(|list| list.iter().map(|(d, _)| *d).collect())
+                .unwrap_or_default()
+        };
+
+        let mut candidates: Option<HashSet<DocId>> = None;
+        let mut excluded: HashSet<DocId> = HashSet::new();
+        let mut score_terms: Vec<String> = Vec::new();
+        let mut phrases: Vec<Vec<String>> = Vec::new();
+
+        let mut intersect = |candidates: &mut Option<HashSet<DocId>>, doc_ids: HashSet<DocId>| {
+            *candidates = Some(match candidates.take() {
+                Some(existing) => existing.intersection(&doc_ids).copied().collect(),
+                None => doc_ids,
+            });
+        };
+
+        // Run each node's raw term(s) through the same analyzer used to index
+        // documents, so e.g. a stemmed query term lines up with stemmed postings.
+        for node in &nodes {
+            match node {
+                QueryNode::Must(term) => {
+                    let Some(term) = self.analyze_single_term(term) else { continue };
+                    intersect(&mut candidates, doc_ids_for(&term));
+                    score_terms.push(term);
+                }
+                QueryNode::MustNot(term) => {
+                    if let Some(term) = self.analyze_single_term(term) {
+                        excluded.extend(doc_ids_for(&term));
                     }
                 }
+                QueryNode::Should(term) => {
+                    if let Some(term) = self.analyze_single_term(term) {
+                        score_terms.push(term);
+                    }
+                }
+                QueryNode::Phrase(terms) => {
+                    let terms = self.analyzer.analyze_tokens(terms.clone());
+                    if terms.is_empty() {
+                        continue;
+                    }
+                    if let Some(first) = terms.first() {
+                        intersect(&mut candidates, doc_ids_for(first));
+                    }
+                    score_terms.extend(terms.clone());
+                    phrases.push(terms);
+                }
+            }
+        }
 
-                (id.clone(), score)
-            })
-            .filter(|(_, score)| *score > 0.0)
-            .collect();
+        // No Must/Phrase clause narrowed the set: every indexed document is a
+        // candidate, same as a plain `Should`-only query.
+        let candidate_ids: HashSet<DocId> =
+            candidates.unwrap_or_else(|| docs.keys().copied().collect());
+
+        let mut scores: HashMap<DocId, f32> = HashMap::new();
+        for term in &score_terms {
+            let list = match postings.get(term) {
+                Some(list) => list,
+                None => continue,
+            };
+            let df = list.len() as f32;
+            let idf = ((n - df + 0.5) / (df + 0.5) + 1.0).ln();
+
+            for (doc_id, tf) in list {
+                if !candidate_ids.contains(doc_id) || excluded.contains(doc_id) {
+                    continue;
+                }
 
-        // Sort by score descending
-        scores.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+                let doc_len = docs
+                    .get(doc_id)
+                    .map(|(_, _, len)| *len as f32)
+                    .unwrap_or(0.0);
+                let tf = *tf as f32;
 
-        // Return top_k results
-        scores.truncate(top_k);
-        scores
+                let tf_norm = (tf * (self.k1 + 1.0))
+                    / (tf + self.k1 * (1.0 - self.b + self.b * doc_len / avg_doc_len));
+
+                *scores.entry(*doc_id).or_insert(0.0) += idf * tf_norm;
+            }
+        }
+
+        // Drop any candidate whose token sequence doesn't actually contain
+        // each phrase as an adjacent run, even though it passed the
+        // first-term posting-list filter above.
+        if !phrases.is_empty() {
+            scores.retain(|doc_id, _| {
+                let tokens = match doc_tokens.get(doc_id) {
+                    Some(tokens) => tokens,
+                    None => return false,
+                };
+                phrases.iter().all(|phrase| contains_phrase(tokens, phrase))
+            });
+        }
+
+        top_k_by_score(scores, top_k)
+            .into_iter()
+            .filter_map(|(doc_id, score)| {
+                docs.get(&doc_id).map(|(id, _, _)| (id.clone(), score))
+            })
+            .collect()
     }
 
     /// Get document content by ID
     pub fn get_document(&self, id: &str) -> Option<String> {
-        self.documents.read().get(id).map(|(content, _)| content.clone())
+        let doc_id = *self.id_to_doc.read().get(id)?;
+        self.documents
+            .read()
+            .get(&doc_id)
+            .map(|(_, content, _)| content.clone())
     }
 
     /// Clear all documents from the index
     pub fn clear(&self) {
         self.documents.write().clear();
-        self.doc_freq.write().clear();
+        self.id_to_doc.write().clear();
+        self.postings.write().clear();
+        self.doc_tokens.write().clear();
         *self.avg_doc_len.write() = 0.0;
+        *self.next_doc_id.write() = 0;
     }
 
     /// Get the number of documents in the index
@@ -175,6 +381,131 @@ impl BM25Index {
     pub fn is_empty(&self) -> bool {
         self.documents.read().is_empty()
     }
+
+    /// Persist the index to `path` as JSON, so a built resume/JD index
+    /// survives a process restart without re-indexing from scratch.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let snapshot = BM25Snapshot {
+            documents: self.documents.read().clone(),
+            id_to_doc: self.id_to_doc.read().clone(),
+            postings: self.postings.read().clone(),
+            doc_tokens: self.doc_tokens.read().clone(),
+            avg_doc_len: *self.avg_doc_len.read(),
+            next_doc_id: *self.next_doc_id.read(),
+            k1: self.k1,
+            b: self.b,
+        };
+
+        let json = serde_json::to_vec(&snapshot).context("Failed to serialize BM25 index")?;
+        std::fs::write(path, json).context("Failed to write BM25 index snapshot")?;
+        Ok(())
+    }
+
+    /// Load an index previously written by `save`, using the default `Analyzer`.
+    pub fn load(path: &Path) -> Result<Self> {
+        Self::load_with_analyzer(path, Analyzer::new())
+    }
+
+    /// Load an index previously written by `save`, using a custom `Analyzer`.
+    ///
+    /// The `Analyzer` itself isn't part of the snapshot — it's configuration,
+    /// not indexed data — so callers that index with a non-default `Analyzer`
+    /// (e.g. stemming enabled) need to pass the same one back in here to keep
+    /// queries aligned with the terms that were actually indexed.
+    pub fn load_with_analyzer(path: &Path, analyzer: Analyzer) -> Result<Self> {
+        let bytes = std::fs::read(path).context("Failed to read BM25 index snapshot")?;
+        let snapshot: BM25Snapshot =
+            serde_json::from_slice(&bytes).context("Failed to deserialize BM25 index")?;
+
+        Ok(Self {
+            documents: RwLock::new(snapshot.documents),
+            id_to_doc: RwLock::new(snapshot.id_to_doc),
+            postings: RwLock::new(snapshot.postings),
+            doc_tokens: RwLock::new(snapshot.doc_tokens),
+            avg_doc_len: RwLock::new(snapshot.avg_doc_len),
+            next_doc_id: RwLock::new(snapshot.next_doc_id),
+            analyzer,
+            k1: snapshot.k1,
+            b: snapshot.b,
+        })
+    }
+}
+
+/// On-disk representation of a `BM25Index`. A plain struct rather than
+/// deriving `Serialize`/`Deserialize` directly on `BM25Index`, since its
+/// fields are behind `RwLock`s that don't implement either.
+#[derive(Serialize, Deserialize)]
+struct BM25Snapshot {
+    documents: HashMap<DocId, (String, String, u32)>,
+    id_to_doc: HashMap<String, DocId>,
+    postings: HashMap<String, Vec<(DocId, u32)>>,
+    doc_tokens: HashMap<DocId, Vec<String>>,
+    avg_doc_len: f32,
+    next_doc_id: DocId,
+    k1: f32,
+    b: f32,
+}
+
+/// Order by score so a min-heap of bounded size `top_k` can evict the
+/// lowest-scoring candidate in O(log top_k) instead of sorting every scored
+/// document.
+struct ScoredDoc {
+    doc_id: DocId,
+    score: f32,
+}
+
+impl PartialEq for ScoredDoc {
+    fn eq(&self, other: &Self) -> bool {
+        self.score == other.score
+    }
+}
+impl Eq for ScoredDoc {}
+
+impl PartialOrd for ScoredDoc {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ScoredDoc {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so `BinaryHeap` (a max-heap) behaves as a min-heap on score.
+        other
+            .score
+            .partial_cmp(&self.score)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Whether `phrase` appears in `tokens` as an adjacent, in-order run.
+fn contains_phrase(tokens: &[String], phrase: &[String]) -> bool {
+    if phrase.is_empty() || phrase.len() > tokens.len() {
+        return false;
+    }
+    tokens.windows(phrase.len()).any(|window| window == phrase)
+}
+
+/// Return the `top_k` highest-scoring (doc, score) pairs, descending by
+/// score, using a bounded min-heap rather than sorting the entire `scores` map.
+fn top_k_by_score(scores: HashMap<DocId, f32>, top_k: usize) -> Vec<(DocId, f32)> {
+    if top_k == 0 {
+        return vec![];
+    }
+
+    let mut heap: BinaryHeap<ScoredDoc> = BinaryHeap::with_capacity(top_k + 1);
+    for (doc_id, score) in scores {
+        if score <= 0.0 {
+            continue;
+        }
+        heap.push(ScoredDoc { doc_id, score });
+        if heap.len() > top_k {
+            heap.pop();
+        }
+    }
+
+    let mut results: Vec<(DocId, f32)> = heap.into_iter().map(|d| (d.doc_id, d.score)).collect();
+    results.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+    results
 }
 
 #[cfg(test)]
@@ -211,4 +542,136 @@ mod tests {
         index.clear();
         assert!(index.is_empty());
     }
+
+    #[test]
+    fn test_search_ranking_matches_reference_scores() {
+        // Scores follow the BM25 formula (k1 = 1.5, b = 0.75) over analyzed
+        // tokens. With the stopword "with" removed, docs 1 and 2 both have 5
+        // tokens and contain each query term once, so they tie: per term
+        // idf = ln(1.6), avg_len = 14/3, giving 3 * 0.45537 = 1.3661 each.
+        let index = BM25Index::new();
+        index.add_document("1", "machine learning engineer python tensorflow");
+        index.add_document("2", "python developer with machine learning experience");
+        index.add_document("3", "graphic designer with photoshop skills");
+
+        let results = index.search("machine learning python", 10);
+        assert_eq!(results.len(), 2);
+        let ids: Vec<_> = results.iter().map(|(id, _)| id.as_str()).collect();
+        assert!(ids.contains(&"1") && ids.contains(&"2"));
+        for (_, score) in &results {
+            assert!((score - 1.3661).abs() < 1e-3, "unexpected score {}", score);
+        }
+    }
+
+    #[test]
+    fn test_remove_document_prunes_postings() {
+        let index = BM25Index::new();
+        index.add_document("1", "quick brown fox");
+        index.add_document("2", "quick brown cat");
+
+        index.remove_document("1");
+        assert_eq!(index.len(), 1);
+        assert!(index.get_document("1").is_none());
+
+        let results = index.search("quick brown", 10);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, "2");
+    }
+
+    #[test]
+    fn test_reindexing_existing_id_does_not_duplicate() {
+        let index = BM25Index::new();
+        index.add_document("1", "quick brown fox");
+        index.add_document("1", "updated content about cats");
+
+        assert_eq!(index.len(), 1);
+        assert_eq!(
+            index.get_document("1").as_deref(),
+            Some("updated content about cats")
+        );
+        assert!(index.search("fox", 10).is_empty());
+    }
+
+    #[test]
+    fn test_search_query_quoted_phrase_requires_adjacency() {
+        let index = BM25Index::new();
+        index.add_document("1", "experience in machine learning and deep learning");
+        index.add_document("2", "learning new skills, especially machine repair");
+
+        // Doc 2 contains both words but not adjacent in this order.
+        let results = index.search_query("\"machine learning\"", 10);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, "1");
+    }
+
+    #[test]
+    fn test_search_query_must_filters_out_missing_term() {
+        let index = BM25Index::new();
+        index.add_document("1", "python backend engineer");
+        index.add_document("2", "java backend engineer");
+
+        let results = index.search_query("+python backend", 10);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, "1");
+    }
+
+    #[test]
+    fn test_search_query_must_not_excludes_matching_docs() {
+        let index = BM25Index::new();
+        index.add_document("1", "python backend engineer");
+        index.add_document("2", "python frontend engineer");
+
+        let results = index.search_query("python -frontend", 10);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, "1");
+    }
+
+    #[test]
+    fn test_contains_phrase_checks_adjacency_and_order() {
+        let tokens = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        assert!(contains_phrase(&tokens, &["a".to_string(), "b".to_string()]));
+        assert!(!contains_phrase(&tokens, &["b".to_string(), "a".to_string()]));
+        assert!(!contains_phrase(&tokens, &["a".to_string(), "c".to_string()]));
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip_matches_original_scores() {
+        let index = BM25Index::new();
+        index.add_document("1", "machine learning engineer python tensorflow");
+        index.add_document("2", "python developer with machine learning experience");
+        index.add_document("3", "graphic designer with photoshop skills");
+        // Exercise remove + re-add before persisting, so the snapshot covers
+        // an index that's actually been mutated rather than just built once.
+        index.remove_document("3");
+        index.add_document("3", "python data scientist");
+
+        let path = std::env::temp_dir().join(format!(
+            "bm25_index_test_{}.json",
+            std::process::id()
+        ));
+        index.save(&path).unwrap();
+        let reloaded = BM25Index::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(reloaded.len(), index.len());
+        assert_eq!(
+            reloaded.search("machine learning python", 10),
+            index.search("machine learning python", 10)
+        );
+        assert_eq!(
+            reloaded.get_document("3").as_deref(),
+            Some("python data scientist")
+        );
+    }
+
+    #[test]
+    fn test_top_k_by_score_bounds_and_orders() {
+        let mut scores = HashMap::new();
+        scores.insert(1u32, 0.5);
+        scores.insert(2u32, 2.0);
+        scores.insert(3u32, 1.0);
+
+        let top = top_k_by_score(scores, 2);
+        assert_eq!(top, vec![(2, 2.0), (3, 1.0)]);
+    }
 }
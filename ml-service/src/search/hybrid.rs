@@ -1,8 +1,9 @@
 use anyhow::Result;
+use futures::future::join_all;
 use std::collections::HashMap;
-use tracing::debug;
+use tracing::{debug, warn};
 
-use super::{BM25Index, QdrantClient, SearchResult, SearchSource};
+use super::{BM25Index, FusionMethod, QdrantClient, ScoreDetails, SearchResult, SearchSource};
 use crate::embedding;
 
 /// Search mode configuration
@@ -10,12 +11,120 @@ use crate::embedding;
 pub enum SearchMode {
     Vector,
     BM25,
-    Hybrid { vector_weight: f32 },
+    /// Reciprocal Rank Fusion, weighted between the vector and BM25 rankings.
+    ///
+    /// `keyword_confidence`, if set, lets the BM25 leg run first and skip the
+    /// (comparatively expensive) query embedding and vector leg entirely when
+    /// the keyword results are already strong — see `hybrid_search`.
+    ///
+    /// `distribution_shift`, if set (or once enough vector scores have been
+    /// observed to estimate one online), recenters the narrow cosine-similarity
+    /// band onto a [0,1] scale before the vector score is used — see
+    /// `DistributionShift`.
+    Hybrid {
+        vector_weight: f32,
+        keyword_confidence: Option<f32>,
+        distribution_shift: Option<DistributionShift>,
+    },
+    /// Convex combination of independently min-max normalized vector and BM25
+    /// scores: `final = semantic_ratio * semantic + (1 - semantic_ratio) * keyword`.
+    /// Unlike `Hybrid`'s RRF, this gives continuous control over keyword-vs-semantic
+    /// emphasis rather than a fixed rank-based blend.
+    Linear { semantic_ratio: f32 },
+}
+
+/// Per-embedder distribution-shift parameters for a raw cosine-similarity
+/// score. Embedding models tend to cluster scores in a narrow band (often
+/// ~0.6-0.9), which biases a fusion blend against BM25's much wider score
+/// range; this recenters that band around 0.5 and spreads it across [0,1] so
+/// it competes fairly.
+///
+/// `std_dev == 0.0` disables shifting (the sane default) — entries are
+/// compared raw, same as before this existed.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DistributionShift {
+    pub mean: f32,
+    pub std_dev: f32,
+}
+
+impl DistributionShift {
+    fn apply(&self, score: f32) -> f32 {
+        if self.std_dev == 0.0 {
+            return score;
+        }
+        (0.5 + (score - self.mean) / (3.0 * self.std_dev)).clamp(0.0, 1.0)
+    }
+}
+
+/// How many of the most recent raw vector scores `VectorScoreStats` keeps
+/// around to estimate a `DistributionShift` online when the caller doesn't
+/// supply one explicitly.
+const VECTOR_SCORE_WINDOW: usize = 200;
+
+/// Minimum number of observed scores before an online estimate is trusted;
+/// below this, shifting is skipped rather than built on a handful of samples.
+const VECTOR_SCORE_MIN_SAMPLES: usize = 10;
+
+/// Rolling window of raw vector scores observed across searches against the
+/// active embedding model, used to estimate a `DistributionShift` when the
+/// caller doesn't configure one.
+struct VectorScoreStats {
+    window: parking_lot::Mutex<std::collections::VecDeque<f32>>,
+}
+
+impl VectorScoreStats {
+    fn new() -> Self {
+        Self {
+            window: parking_lot::Mutex::new(std::collections::VecDeque::with_capacity(
+                VECTOR_SCORE_WINDOW,
+            )),
+        }
+    }
+
+    fn observe(&self, score: f32) {
+        let mut window = self.window.lock();
+        if window.len() >= VECTOR_SCORE_WINDOW {
+            window.pop_front();
+        }
+        window.push_back(score);
+    }
+
+    fn estimate(&self) -> Option<DistributionShift> {
+        let window = self.window.lock();
+        if window.len() < VECTOR_SCORE_MIN_SAMPLES {
+            return None;
+        }
+
+        let n = window.len() as f32;
+        let mean = window.iter().sum::<f32>() / n;
+        let variance = window.iter().map(|s| (s - mean).powi(2)).sum::<f32>() / n;
+
+        Some(DistributionShift {
+            mean,
+            std_dev: variance.sqrt(),
+        })
+    }
+}
+
+/// One collection's sub-query within `search_federated_queries`: its own
+/// query text and filters alongside a relative weight, so e.g. a "resumes"
+/// collection and a "job_descriptions" collection can be searched with
+/// different queries in the same request.
+#[derive(Debug, Clone)]
+pub struct FederatedQuery {
+    pub collection: String,
+    pub query: String,
+    pub filters: Option<HashMap<String, String>>,
+    pub weight: f32,
 }
 
 impl Default for SearchMode {
     fn default() -> Self {
-        SearchMode::Hybrid { vector_weight: 0.7 }
+        SearchMode::Hybrid {
+            vector_weight: 0.7,
+            keyword_confidence: None,
+            distribution_shift: None,
+        }
     }
 }
 
@@ -24,14 +133,38 @@ pub struct HybridSearch {
     qdrant: QdrantClient,
     bm25_indices: parking_lot::RwLock<HashMap<String, BM25Index>>,
     rrf_k: usize,
+    /// Minimum vector score a hit must clear to be returned at all.
+    min_score_vector: f32,
+    /// Minimum BM25 score a hit must clear to be returned at all.
+    min_score_text: f32,
+    /// Rolling window of observed raw vector scores, used to estimate a
+    /// `DistributionShift` when a hybrid search doesn't configure one.
+    vector_score_stats: VectorScoreStats,
 }
 
 impl HybridSearch {
     pub fn new(qdrant: QdrantClient, rrf_k: usize) -> Self {
+        Self::with_min_scores(qdrant, rrf_k, 0.0, 0.0)
+    }
+
+    /// Build a `HybridSearch` that drops vector hits below `min_score_vector`
+    /// and BM25 hits below `min_score_text` before they ever reach fusion.
+    /// Because the two sources' scores live on different scales, each
+    /// threshold is applied independently to its own source's result list
+    /// rather than to the fused score.
+    pub fn with_min_scores(
+        qdrant: QdrantClient,
+        rrf_k: usize,
+        min_score_vector: f32,
+        min_score_text: f32,
+    ) -> Self {
         Self {
             qdrant,
             bm25_indices: parking_lot::RwLock::new(HashMap::new()),
             rrf_k,
+            min_score_vector,
+            min_score_text,
+            vector_score_stats: VectorScoreStats::new(),
         }
     }
 
@@ -72,25 +205,113 @@ impl HybridSearch {
     ) -> Result<Vec<SearchResult>> {
         match mode {
             SearchMode::Vector => {
-                self.vector_search(collection, query, top_k, filters).await
+                self.vector_search(collection, query, top_k, filters, None).await
             }
             SearchMode::BM25 => {
                 self.bm25_search(collection, query, top_k)
             }
-            SearchMode::Hybrid { vector_weight } => {
-                self.hybrid_search(collection, query, top_k, vector_weight, filters)
+            SearchMode::Hybrid {
+                vector_weight,
+                keyword_confidence,
+                distribution_shift,
+            } => {
+                self.hybrid_search(
+                    collection,
+                    query,
+                    top_k,
+                    vector_weight,
+                    keyword_confidence,
+                    distribution_shift,
+                    filters,
+                )
+                .await
+            }
+            SearchMode::Linear { semantic_ratio } => {
+                self.linear_search(collection, query, top_k, semantic_ratio, filters)
                     .await
             }
         }
     }
 
-    /// Vector-only search
+    /// Search several collections concurrently, each with its own query text
+    /// and filters, and merge the results into one ranked list. Lets a
+    /// caller query e.g. a "resumes" collection and a "job_descriptions"
+    /// collection in one request, interleaved by weighted relevance. Each
+    /// collection's raw scores are independently recentered via the same
+    /// `DistributionShift` mean/std normalization the hybrid fusion path
+    /// uses — estimated from that collection's own result batch — so a
+    /// small collection's scores can't dominate a large one's merely by
+    /// living on a different scale; a document id that appears in more than
+    /// one collection keeps only its highest weighted hit.
+    pub async fn search_federated_queries(
+        &self,
+        queries: &[FederatedQuery],
+        top_k: usize,
+        mode: SearchMode,
+    ) -> Result<Vec<SearchResult>> {
+        debug!(
+            "Performing federated search across {} collection queries",
+            queries.len()
+        );
+
+        let per_collection = join_all(queries.iter().map(|q| async move {
+            let results = self
+                .search(&q.collection, &q.query, top_k, mode, q.filters.clone())
+                .await;
+            (q.collection.clone(), q.weight, results)
+        }))
+        .await;
+
+        let mut combined = Vec::new();
+        for (collection, weight, results) in per_collection {
+            match results {
+                Ok(results) => {
+                    let shift = Self::estimate_shift(&results);
+                    for mut result in results {
+                        result.score = shift.apply(result.score) * weight;
+                        result
+                            .metadata
+                            .insert("source_collection".to_string(), collection.clone());
+                        combined.push(result);
+                    }
+                }
+                Err(e) => {
+                    warn!("Federated search against '{}' failed: {}", collection, e);
+                }
+            }
+        }
+
+        // De-duplicate by id across collections, keeping the higher weighted score.
+        let mut best: HashMap<String, SearchResult> = HashMap::new();
+        for result in combined {
+            match best.get(&result.id) {
+                Some(existing) if existing.score >= result.score => {}
+                _ => {
+                    best.insert(result.id.clone(), result);
+                }
+            }
+        }
+
+        let mut deduped: Vec<SearchResult> = best.into_values().collect();
+        deduped.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        deduped.truncate(top_k);
+
+        Ok(deduped)
+    }
+
+    /// Vector-only search. `shift`, if given, recenters the raw cosine score
+    /// onto a [0,1] scale before it's returned — see `DistributionShift`. This
+    /// is resolved by the caller (e.g. `hybrid_search`, which falls back to an
+    /// online estimate when fusing against BM25); a standalone `SearchMode::Vector`
+    /// request always passes `None` here so its scores stay raw cosine similarity,
+    /// regardless of what other queries have recently observed.
     async fn vector_search(
         &self,
         collection: &str,
         query: &str,
         top_k: usize,
         filters: Option<HashMap<String, String>>,
+        shift: Option<DistributionShift>,
     ) -> Result<Vec<SearchResult>> {
         debug!("Performing vector search for collection: {}", collection);
 
@@ -98,9 +319,32 @@ impl HybridSearch {
         let query_embedding = embedding::embed(query).await?;
 
         // Search Qdrant
-        self.qdrant
+        let results = self
+            .qdrant
             .search(collection, query_embedding, top_k as u64, filters)
-            .await
+            .await?;
+
+        // Feed the online estimator with raw scores before any filtering or
+        // shifting touches them, regardless of whether this particular call
+        // applies a shift — this is what lets a later hybrid call estimate one.
+        for result in &results {
+            self.vector_score_stats.observe(result.score);
+        }
+
+        let results = Self::apply_min_score(results, self.min_score_vector);
+
+        let results = match shift.filter(|s| s.std_dev > 0.0) {
+            Some(shift) => results
+                .into_iter()
+                .map(|mut r| {
+                    r.score = shift.apply(r.score);
+                    r
+                })
+                .collect(),
+            None => results,
+        };
+
+        Ok(results)
     }
 
     /// BM25-only search
@@ -129,11 +373,21 @@ impl HybridSearch {
                     score,
                     metadata: HashMap::new(),
                     source: SearchSource::BM25,
+                    score_details: None,
                 }
             })
             .collect();
 
-        Ok(results)
+        Ok(Self::apply_min_score(results, self.min_score_text))
+    }
+
+    /// Drop results scoring below `min_score`. Applied per-source before
+    /// fusion, since vector and BM25 scores aren't comparable on the same scale.
+    fn apply_min_score(results: Vec<SearchResult>, min_score: f32) -> Vec<SearchResult> {
+        if min_score <= 0.0 {
+            return results;
+        }
+        results.into_iter().filter(|r| r.score >= min_score).collect()
     }
 
     /// Hybrid search with RRF fusion
@@ -143,6 +397,8 @@ impl HybridSearch {
         query: &str,
         top_k: usize,
         vector_weight: f32,
+        keyword_confidence: Option<f32>,
+        distribution_shift: Option<DistributionShift>,
         filters: Option<HashMap<String, String>>,
     ) -> Result<Vec<SearchResult>> {
         debug!(
@@ -153,26 +409,111 @@ impl HybridSearch {
         // Fetch more results for fusion
         let fetch_k = top_k * 3;
 
+        // Only the hybrid fusion path ever recenters vector scores — a caller
+        // that didn't supply a shift falls back to the online estimate here,
+        // not inside `vector_search` itself, so a standalone `SearchMode::Vector`
+        // query never gets its raw cosine scores silently rewritten.
+        let distribution_shift = distribution_shift
+            .filter(|s| s.std_dev > 0.0)
+            .or_else(|| self.vector_score_stats.estimate());
+
+        // A confidence threshold lets us run the (cheap) BM25 leg first and,
+        // if it already looks good, skip the (comparatively expensive) query
+        // embedding and vector leg entirely. Without a threshold both legs
+        // always run concurrently, same as before this was added.
+        if let Some(threshold) = keyword_confidence {
+            let bm25_results = self.bm25_search(collection, query, fetch_k).unwrap_or_default();
+            let top_score = bm25_results.first().map(|r| r.score).unwrap_or(0.0);
+
+            if bm25_results.len() >= top_k && top_score >= threshold {
+                debug!(
+                    "Keyword results for '{}' are strong enough ({} hits, top score {:.3} >= {:.3}); skipping query embedding",
+                    collection, bm25_results.len(), top_score, threshold
+                );
+
+                let mut results = bm25_results;
+                results.truncate(top_k);
+                Self::mark_embedding_skipped(&mut results);
+                return Ok(results);
+            }
+
+            let vector_results = self
+                .vector_search(collection, query, fetch_k, filters, distribution_shift)
+                .await;
+            let (vector_results, degraded) = Self::unwrap_vector_arm(vector_results, collection);
+
+            let mut fused = self.rrf_fusion(vector_results, bm25_results, vector_weight, top_k);
+            if degraded {
+                Self::mark_degraded(&mut fused);
+            }
+            return Ok(fused);
+        }
+
         // Run both searches concurrently
         let (vector_results, bm25_results) = tokio::join!(
-            self.vector_search(collection, query, fetch_k, filters),
+            self.vector_search(collection, query, fetch_k, filters, distribution_shift),
             async { self.bm25_search(collection, query, fetch_k) }
         );
 
-        let vector_results = vector_results.unwrap_or_default();
+        let (vector_results, degraded) = Self::unwrap_vector_arm(vector_results, collection);
         let bm25_results = bm25_results.unwrap_or_default();
 
         // Apply RRF fusion
-        let fused = self.rrf_fusion(
+        let mut fused = self.rrf_fusion(
             vector_results,
             bm25_results,
             vector_weight,
             top_k,
         );
 
+        if degraded {
+            Self::mark_degraded(&mut fused);
+        }
+
         Ok(fused)
     }
 
+    /// Unwrap the vector arm of a concurrent hybrid/linear search, distinguishing
+    /// a true embedding failure (model not initialized, ONNX crash) from a
+    /// legitimate empty result list. On failure, logs a warning and returns an
+    /// empty vector list with the degraded flag set, so fusion runs on the
+    /// BM25 results alone and the caller can mark them as degraded.
+    fn unwrap_vector_arm(
+        vector_results: Result<Vec<SearchResult>>,
+        collection: &str,
+    ) -> (Vec<SearchResult>, bool) {
+        match vector_results {
+            Ok(results) => (results, false),
+            Err(e) => {
+                warn!(
+                    "Vector search failed for collection '{}', falling back to BM25-only: {}",
+                    collection, e
+                );
+                (Vec::new(), true)
+            }
+        }
+    }
+
+    /// Annotate results from a degraded (vector-arm-failed) fusion so callers
+    /// know the hybrid path silently downgraded to keyword-only search.
+    fn mark_degraded(results: &mut [SearchResult]) {
+        for result in results {
+            result.source = SearchSource::BM25;
+            result.metadata.insert("degraded".to_string(), "true".to_string());
+        }
+    }
+
+    /// Annotate results returned without ever computing a query embedding, so
+    /// callers can observe the lazy-embedding optimization (distinct from
+    /// `mark_degraded`, which marks an embedding that was attempted and failed).
+    fn mark_embedding_skipped(results: &mut [SearchResult]) {
+        for result in results {
+            result
+                .metadata
+                .insert("embedding_skipped".to_string(), "true".to_string());
+        }
+    }
+
     /// Reciprocal Rank Fusion to combine results
     fn rrf_fusion(
         &self,
@@ -184,52 +525,235 @@ impl HybridSearch {
         let bm25_weight = 1.0 - vector_weight;
         let k = self.rrf_k as f32;
 
-        // Build score maps
-        let mut scores: HashMap<String, (f32, Option<SearchResult>)> = HashMap::new();
+        // Per-id fused score plus enough of the raw per-side data to build a
+        // ScoreDetails breakdown once fusion is done.
+        let mut scores: HashMap<String, RrfEntry> = HashMap::new();
 
         // Add vector results with RRF score
         for (rank, result) in vector_results.into_iter().enumerate() {
             let rrf_score = vector_weight * (1.0 / (k + rank as f32 + 1.0));
-            scores
-                .entry(result.id.clone())
-                .and_modify(|(score, _)| *score += rrf_score)
-                .or_insert((rrf_score, Some(result)));
+            let vector_score = result.score;
+            let entry = scores.entry(result.id.clone()).or_default();
+            entry.fused_score += rrf_score;
+            entry.vector_score = Some(vector_score);
+            entry.vector_rank = Some(rank);
+            entry.result.get_or_insert(result);
         }
 
         // Add BM25 results with RRF score
         for (rank, result) in bm25_results.into_iter().enumerate() {
             let rrf_score = bm25_weight * (1.0 / (k + rank as f32 + 1.0));
-            scores
-                .entry(result.id.clone())
-                .and_modify(|(score, existing)| {
-                    *score += rrf_score;
-                    // Keep the more complete result
-                    if existing.is_none() {
-                        *existing = Some(result.clone());
-                    }
-                })
-                .or_insert((rrf_score, Some(result)));
+            let bm25_score = result.score;
+            let entry = scores.entry(result.id.clone()).or_default();
+            entry.fused_score += rrf_score;
+            entry.bm25_score = Some(bm25_score);
+            entry.bm25_rank = Some(rank);
+            entry.result.get_or_insert(result);
         }
 
         // Sort by fused score and take top_k
-        let mut results: Vec<(String, f32, SearchResult)> = scores
+        let mut results: Vec<(f32, SearchResult)> = scores
             .into_iter()
-            .filter_map(|(id, (score, result))| {
-                result.map(|mut r| {
-                    r.score = score;
+            .filter_map(|(_, entry)| {
+                let fused_score = entry.fused_score;
+                entry.result.map(|mut r| {
+                    r.score = fused_score;
                     r.source = SearchSource::Hybrid;
-                    (id, score, r)
+                    r.score_details = Some(ScoreDetails {
+                        vector_score: entry.vector_score,
+                        vector_rank: entry.vector_rank,
+                        bm25_score: entry.bm25_score,
+                        bm25_rank: entry.bm25_rank,
+                        fusion_method: FusionMethod::Rrf,
+                        fusion_weight: Some(vector_weight),
+                        fused_score,
+                        rerank_score: None,
+                        rerank_original_rank: None,
+                        rerank_new_rank: None,
+                    });
+                    (fused_score, r)
                 })
             })
             .collect();
 
-        results.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        results.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
         results.truncate(top_k);
 
-        results.into_iter().map(|(_, _, r)| r).collect()
+        results.into_iter().map(|(_, r)| r).collect()
+    }
+
+    /// Hybrid search with normalized convex-combination (semantic-ratio) fusion
+    async fn linear_search(
+        &self,
+        collection: &str,
+        query: &str,
+        top_k: usize,
+        semantic_ratio: f32,
+        filters: Option<HashMap<String, String>>,
+    ) -> Result<Vec<SearchResult>> {
+        debug!(
+            "Performing linear fusion search for collection: {} (semantic_ratio={})",
+            collection, semantic_ratio
+        );
+
+        let fetch_k = top_k * 3;
+
+        // Linear mode already min-max normalizes both legs independently before
+        // blending, so it doesn't need the distribution shift hybrid mode uses.
+        let (vector_results, bm25_results) = tokio::join!(
+            self.vector_search(collection, query, fetch_k, filters, None),
+            async { self.bm25_search(collection, query, fetch_k) }
+        );
+
+        let (vector_results, degraded) = Self::unwrap_vector_arm(vector_results, collection);
+        let bm25_results = bm25_results.unwrap_or_default();
+
+        let mut fused = Self::linear_fusion(
+            vector_results,
+            bm25_results,
+            semantic_ratio.clamp(0.0, 1.0),
+            top_k,
+        );
+
+        if degraded {
+            Self::mark_degraded(&mut fused);
+        }
+
+        Ok(fused)
+    }
+
+    /// Combine independently min-max normalized vector and BM25 scores via
+    /// `final = semantic_ratio * semantic + (1 - semantic_ratio) * keyword`.
+    /// A document missing from one side is treated as scoring 0 on that side.
+    /// Doesn't touch `self` — pure function of its inputs, same as `min_max_normalize`.
+    fn linear_fusion(
+        vector_results: Vec<SearchResult>,
+        bm25_results: Vec<SearchResult>,
+        semantic_ratio: f32,
+        top_k: usize,
+    ) -> Vec<SearchResult> {
+        let vector_norm = Self::min_max_normalize(&vector_results);
+        let bm25_norm = Self::min_max_normalize(&bm25_results);
+
+        let mut combined: HashMap<String, LinearEntry> = HashMap::new();
+
+        for (rank, (result, semantic)) in vector_results.into_iter().zip(vector_norm).enumerate() {
+            let raw_score = result.score;
+            let entry = combined.entry(result.id.clone()).or_default();
+            entry.semantic = semantic;
+            entry.vector_score = Some(raw_score);
+            entry.vector_rank = Some(rank);
+            entry.result.get_or_insert(result);
+        }
+
+        for (rank, (result, keyword)) in bm25_results.into_iter().zip(bm25_norm).enumerate() {
+            let raw_score = result.score;
+            let entry = combined.entry(result.id.clone()).or_default();
+            entry.keyword = keyword;
+            entry.bm25_score = Some(raw_score);
+            entry.bm25_rank = Some(rank);
+            entry.result.get_or_insert(result);
+        }
+
+        let mut results: Vec<(f32, SearchResult)> = combined
+            .into_iter()
+            .filter_map(|(_, entry)| {
+                let final_score =
+                    semantic_ratio * entry.semantic + (1.0 - semantic_ratio) * entry.keyword;
+                entry.result.map(|mut r| {
+                    r.score = final_score;
+                    r.source = SearchSource::Hybrid;
+                    r.score_details = Some(ScoreDetails {
+                        vector_score: entry.vector_score,
+                        vector_rank: entry.vector_rank,
+                        bm25_score: entry.bm25_score,
+                        bm25_rank: entry.bm25_rank,
+                        fusion_method: FusionMethod::Linear,
+                        fusion_weight: Some(semantic_ratio),
+                        fused_score: final_score,
+                        rerank_score: None,
+                        rerank_original_rank: None,
+                        rerank_new_rank: None,
+                    });
+                    (final_score, r)
+                })
+            })
+            .collect();
+
+        results.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+        results.truncate(top_k);
+
+        results.into_iter().map(|(_, r)| r).collect()
+    }
+
+    /// Min-max normalize scores into `[0, 1]`. An empty or zero-range input
+    /// maps every score to `1.0` so a single-result list still contributes fully.
+    fn min_max_normalize(results: &[SearchResult]) -> Vec<f32> {
+        if results.is_empty() {
+            return vec![];
+        }
+
+        let min = results.iter().map(|r| r.score).fold(f32::INFINITY, f32::min);
+        let max = results
+            .iter()
+            .map(|r| r.score)
+            .fold(f32::NEG_INFINITY, f32::max);
+        let range = max - min;
+
+        results
+            .iter()
+            .map(|r| if range > 0.0 { (r.score - min) / range } else { 1.0 })
+            .collect()
+    }
+
+    /// Estimate a `DistributionShift` from a single batch's own mean/std,
+    /// mirroring `VectorScoreStats::estimate` but scoped to one collection's
+    /// result set instead of a rolling cross-query window. Unlike
+    /// `min_max_normalize`, this doesn't pin the batch's best/worst hit to
+    /// exactly 1.0/0.0: the best hit lands at `0.5 + (max - mean) / (3 * std_dev)`.
+    /// The batch's absolute score level is not kept, so two batches with the
+    /// same spread map to the same values however weak or strong they are. An empty
+    /// or single-item batch has no meaningful spread, so `std_dev` is left at
+    /// `0.0`, which makes `apply` a no-op.
+    fn estimate_shift(results: &[SearchResult]) -> DistributionShift {
+        if results.len() < 2 {
+            return DistributionShift::default();
+        }
+
+        let n = results.len() as f32;
+        let mean = results.iter().map(|r| r.score).sum::<f32>() / n;
+        let variance = results.iter().map(|r| (r.score - mean).powi(2)).sum::<f32>() / n;
+
+        DistributionShift {
+            mean,
+            std_dev: variance.sqrt(),
+        }
     }
 }
 
+/// Accumulator used while building an RRF-fused result for one document id.
+#[derive(Default)]
+struct RrfEntry {
+    fused_score: f32,
+    result: Option<SearchResult>,
+    vector_score: Option<f32>,
+    vector_rank: Option<usize>,
+    bm25_score: Option<f32>,
+    bm25_rank: Option<usize>,
+}
+
+/// Accumulator used while building a linear-fused result for one document id.
+#[derive(Default)]
+struct LinearEntry {
+    semantic: f32,
+    keyword: f32,
+    result: Option<SearchResult>,
+    vector_score: Option<f32>,
+    vector_rank: Option<usize>,
+    bm25_score: Option<f32>,
+    bm25_rank: Option<usize>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -238,10 +762,194 @@ mod tests {
     fn test_search_mode_default() {
         let mode = SearchMode::default();
         match mode {
-            SearchMode::Hybrid { vector_weight } => {
+            SearchMode::Hybrid {
+                vector_weight,
+                keyword_confidence,
+                distribution_shift,
+            } => {
                 assert!((vector_weight - 0.7).abs() < 0.001);
+                assert!(keyword_confidence.is_none());
+                assert!(distribution_shift.is_none());
             }
             _ => panic!("Expected hybrid mode as default"),
         }
     }
+
+    #[test]
+    fn test_distribution_shift_noop_when_std_dev_zero() {
+        let shift = DistributionShift {
+            mean: 0.5,
+            std_dev: 0.0,
+        };
+        assert_eq!(shift.apply(0.83), 0.83);
+    }
+
+    #[test]
+    fn test_distribution_shift_recenters_score() {
+        let shift = DistributionShift {
+            mean: 0.5,
+            std_dev: 0.1,
+        };
+        // score == mean recenters to 0.5
+        assert!((shift.apply(0.5) - 0.5).abs() < 0.0001);
+        // one std_dev above the mean adds 1/3 (0.5 -> ~0.833)
+        assert!((shift.apply(0.6) - 0.8333).abs() < 0.001);
+        // extreme scores still clamp into [0, 1]
+        assert_eq!(shift.apply(10.0), 1.0);
+        assert_eq!(shift.apply(-10.0), 0.0);
+    }
+
+    #[test]
+    fn test_vector_score_stats_requires_minimum_samples() {
+        let stats = VectorScoreStats::new();
+        for _ in 0..VECTOR_SCORE_MIN_SAMPLES - 1 {
+            stats.observe(0.5);
+        }
+        assert!(stats.estimate().is_none());
+
+        stats.observe(0.5);
+        assert!(stats.estimate().is_some());
+    }
+
+    fn result(id: &str, score: f32, source: SearchSource) -> SearchResult {
+        SearchResult {
+            id: id.to_string(),
+            content: String::new(),
+            score,
+            metadata: HashMap::new(),
+            source,
+            score_details: None,
+        }
+    }
+
+    #[test]
+    fn test_min_max_normalize_guards_equal_scores() {
+        let results = vec![
+            result("a", 0.5, SearchSource::Vector),
+            result("b", 0.5, SearchSource::Vector),
+        ];
+        let normalized = HybridSearch::min_max_normalize(&results);
+        assert_eq!(normalized, vec![1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_min_max_normalize_spreads_distinct_scores() {
+        let results = vec![
+            result("a", 0.0, SearchSource::Vector),
+            result("b", 5.0, SearchSource::Vector),
+            result("c", 10.0, SearchSource::Vector),
+        ];
+        let normalized = HybridSearch::min_max_normalize(&results);
+        assert_eq!(normalized, vec![0.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn test_estimate_shift_noop_below_two_results() {
+        let shift = HybridSearch::estimate_shift(&[result("a", 0.9, SearchSource::Vector)]);
+        assert_eq!(shift.std_dev, 0.0);
+        assert_eq!(shift.apply(0.9), 0.9);
+    }
+
+    #[test]
+    fn test_estimate_shift_recenters_without_pinning_to_unit_range() {
+        // mean 0.2, std_dev ~0.0816: the best hit (0.3) maps to
+        // 0.5 + 0.1 / 0.2449 ~= 0.908, below the 1.0 that min-max normalize
+        // would give it.
+        let weak_collection = vec![
+            result("a", 0.1, SearchSource::Vector),
+            result("b", 0.2, SearchSource::Vector),
+            result("c", 0.3, SearchSource::Vector),
+        ];
+        let shift = HybridSearch::estimate_shift(&weak_collection);
+        let top_score = shift.apply(0.3);
+        assert!((top_score - 0.9082).abs() < 0.001, "unexpected top score: {}", top_score);
+        assert!(top_score < 1.0, "best hit got pinned to 1.0: {}", top_score);
+    }
+
+    #[test]
+    fn test_linear_fusion_missing_source_scores_zero_on_that_side() {
+        // "a" only has a vector hit, "b" only has a BM25 hit; each should be
+        // scored purely on the side it's present on, not dropped or penalized
+        // beyond that.
+        let vector_results = vec![result("a", 1.0, SearchSource::Vector)];
+        let bm25_results = vec![result("b", 1.0, SearchSource::BM25)];
+
+        let fused = HybridSearch::linear_fusion(vector_results, bm25_results, 0.5, 10);
+
+        assert_eq!(fused.len(), 2);
+        let a = fused.iter().find(|r| r.id == "a").unwrap();
+        let b = fused.iter().find(|r| r.id == "b").unwrap();
+        // Single-result min-max normalize maps to 1.0, so each is
+        // semantic_ratio or (1 - semantic_ratio) of that, with the other side at 0.
+        assert!((a.score - 0.5).abs() < 0.001);
+        assert!((b.score - 0.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_linear_fusion_weights_toward_semantic_ratio() {
+        let vector_results = vec![
+            result("a", 0.0, SearchSource::Vector),
+            result("b", 10.0, SearchSource::Vector),
+        ];
+        let bm25_results = vec![
+            result("a", 10.0, SearchSource::BM25),
+            result("b", 0.0, SearchSource::BM25),
+        ];
+
+        // semantic_ratio = 1.0 should rank purely on the vector leg ("b" wins).
+        let fused = HybridSearch::linear_fusion(vector_results.clone(), bm25_results.clone(), 1.0, 10);
+        assert_eq!(fused[0].id, "b");
+
+        // semantic_ratio = 0.0 should rank purely on the keyword leg ("a" wins).
+        let fused = HybridSearch::linear_fusion(vector_results, bm25_results, 0.0, 10);
+        assert_eq!(fused[0].id, "a");
+    }
+
+    #[test]
+    fn test_linear_fusion_truncates_to_top_k() {
+        let vector_results = vec![
+            result("a", 1.0, SearchSource::Vector),
+            result("b", 2.0, SearchSource::Vector),
+            result("c", 3.0, SearchSource::Vector),
+        ];
+        let fused = HybridSearch::linear_fusion(vector_results, vec![], 1.0, 2);
+        assert_eq!(fused.len(), 2);
+        assert_eq!(fused[0].id, "c");
+        assert_eq!(fused[1].id, "b");
+    }
+
+    #[test]
+    fn test_unwrap_vector_arm_falls_back_on_error() {
+        let (results, degraded) =
+            HybridSearch::unwrap_vector_arm(Err(anyhow::anyhow!("model not initialized")), "resumes");
+        assert!(results.is_empty());
+        assert!(degraded);
+    }
+
+    #[test]
+    fn test_unwrap_vector_arm_passes_through_success() {
+        let (results, degraded) =
+            HybridSearch::unwrap_vector_arm(Ok(vec![result("a", 1.0, SearchSource::Vector)]), "resumes");
+        assert_eq!(results.len(), 1);
+        assert!(!degraded);
+    }
+
+    #[test]
+    fn test_mark_degraded_annotates_results() {
+        let mut results = vec![result("a", 1.0, SearchSource::Hybrid)];
+        HybridSearch::mark_degraded(&mut results);
+        assert_eq!(results[0].source, SearchSource::BM25);
+        assert_eq!(results[0].metadata.get("degraded").map(String::as_str), Some("true"));
+    }
+
+    #[test]
+    fn test_mark_embedding_skipped_annotates_results_without_changing_source() {
+        let mut results = vec![result("a", 1.0, SearchSource::BM25)];
+        HybridSearch::mark_embedding_skipped(&mut results);
+        assert_eq!(results[0].source, SearchSource::BM25);
+        assert_eq!(
+            results[0].metadata.get("embedding_skipped").map(String::as_str),
+            Some("true")
+        );
+    }
 }
@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+
+use super::BM25Index;
+
+/// Self-contained BM25 + dense-vector hybrid index for small, in-memory
+/// corpora that don't warrant a Qdrant collection (tests, scratch scripts,
+/// offline scoring). `HybridSearch` plays this same role against a real
+/// Qdrant-backed corpus; this is the embedded equivalent.
+pub struct HybridIndex {
+    bm25: BM25Index,
+    /// Dense vectors keyed by document id, searched via brute-force cosine
+    /// similarity — fine at the scale this type is meant for.
+    vectors: HashMap<String, Vec<f32>>,
+    rrf_k: f32,
+}
+
+/// How `HybridIndex::search` combines the BM25 and vector rankings.
+#[derive(Debug, Clone, Copy)]
+pub enum FusionMode {
+    /// Reciprocal Rank Fusion: `score(d) = Σ 1/(k + rank_r(d))` over retrievers
+    /// `r` that ranked `d`; a retriever that didn't rank `d` contributes 0.
+    Rrf,
+    /// `alpha * bm25_norm + (1 - alpha) * cosine_norm`, with both retrievers'
+    /// raw scores independently min-max normalized to `[0, 1]` first.
+    WeightedSum { alpha: f32 },
+}
+
+impl Default for HybridIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HybridIndex {
+    pub fn new() -> Self {
+        Self {
+            bm25: BM25Index::new(),
+            vectors: HashMap::new(),
+            rrf_k: 60.0,
+        }
+    }
+
+    /// Add a document with both its text (indexed into BM25) and its dense
+    /// embedding (kept for cosine similarity at query time).
+    pub fn add_document(&mut self, id: &str, content: &str, embedding: Vec<f32>) {
+        self.bm25.add_document(id, content);
+        self.vectors.insert(id.to_string(), embedding);
+    }
+
+    pub fn clear(&mut self) {
+        self.bm25.clear();
+        self.vectors.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.bm25.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bm25.is_empty()
+    }
+
+    /// Run both retrievers and fuse their rankings with `mode`.
+    pub fn search(
+        &self,
+        query_text: &str,
+        query_embedding: &[f32],
+        top_k: usize,
+        mode: FusionMode,
+    ) -> Vec<(String, f32)> {
+        let bm25_ranked = self.bm25.search(query_text, self.vectors.len().max(top_k));
+        let vector_ranked = self.vector_search(query_embedding, self.vectors.len().max(top_k));
+
+        let fused = match mode {
+            FusionMode::Rrf => self.rrf_fuse(&bm25_ranked, &vector_ranked),
+            FusionMode::WeightedSum { alpha } => {
+                self.weighted_sum_fuse(&bm25_ranked, &vector_ranked, alpha.clamp(0.0, 1.0))
+            }
+        };
+
+        let mut fused: Vec<(String, f32)> = fused.into_iter().collect();
+        fused.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        fused.truncate(top_k);
+        fused
+    }
+
+    /// Brute-force cosine similarity against every stored vector.
+    fn vector_search(&self, query_embedding: &[f32], top_k: usize) -> Vec<(String, f32)> {
+        let mut scored: Vec<(String, f32)> = self
+            .vectors
+            .iter()
+            .map(|(id, vec)| (id.clone(), cosine_similarity(query_embedding, vec)))
+            .collect();
+
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        scored.truncate(top_k);
+        scored
+    }
+
+    fn rrf_fuse(
+        &self,
+        bm25_ranked: &[(String, f32)],
+        vector_ranked: &[(String, f32)],
+    ) -> HashMap<String, f32> {
+        let mut scores: HashMap<String, f32> = HashMap::new();
+
+        for (rank, (id, _)) in bm25_ranked.iter().enumerate() {
+            *scores.entry(id.clone()).or_insert(0.0) += 1.0 / (self.rrf_k + rank as f32 + 1.0);
+        }
+        for (rank, (id, _)) in vector_ranked.iter().enumerate() {
+            *scores.entry(id.clone()).or_insert(0.0) += 1.0 / (self.rrf_k + rank as f32 + 1.0);
+        }
+
+        scores
+    }
+
+    fn weighted_sum_fuse(
+        &self,
+        bm25_ranked: &[(String, f32)],
+        vector_ranked: &[(String, f32)],
+        alpha: f32,
+    ) -> HashMap<String, f32> {
+        let bm25_norm = min_max_normalize(bm25_ranked);
+        let vector_norm = min_max_normalize(vector_ranked);
+
+        let mut scores: HashMap<String, f32> = HashMap::new();
+        for (id, score) in bm25_norm {
+            *scores.entry(id).or_insert(0.0) += alpha * score;
+        }
+        for (id, score) in vector_norm {
+            *scores.entry(id).or_insert(0.0) += (1.0 - alpha) * score;
+        }
+
+        scores
+    }
+}
+
+/// Min-max normalize a ranked `(id, score)` list into `[0, 1]`. An empty or
+/// zero-range input maps every score to `1.0` so a single result still
+/// contributes fully.
+fn min_max_normalize(ranked: &[(String, f32)]) -> Vec<(String, f32)> {
+    if ranked.is_empty() {
+        return vec![];
+    }
+
+    let min = ranked.iter().map(|(_, s)| *s).fold(f32::INFINITY, f32::min);
+    let max = ranked
+        .iter()
+        .map(|(_, s)| *s)
+        .fold(f32::NEG_INFINITY, f32::max);
+    let range = max - min;
+
+    ranked
+        .iter()
+        .map(|(id, score)| {
+            let normalized = if range > 0.0 { (score - min) / range } else { 1.0 };
+            (id.clone(), normalized)
+        })
+        .collect()
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+
+    dot / (norm_a * norm_b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn small_corpus() -> HybridIndex {
+        let mut index = HybridIndex::new();
+        index.add_document(
+            "ml_engineer",
+            "machine learning engineer python tensorflow",
+            vec![1.0, 0.0, 0.0],
+        );
+        index.add_document(
+            "designer",
+            "graphic designer with photoshop skills",
+            vec![0.0, 1.0, 0.0],
+        );
+        index.add_document(
+            "data_scientist",
+            "data scientist machine learning python statistics",
+            vec![0.9, 0.1, 0.0],
+        );
+        index
+    }
+
+    #[test]
+    fn test_rrf_fuse_prefers_documents_ranked_by_both_retrievers() {
+        let index = small_corpus();
+        // Query text favors the two ML documents; query embedding favors
+        // "ml_engineer" directly, so it should come out on top under RRF.
+        let results = index.search("machine learning python", &[1.0, 0.0, 0.0], 3, FusionMode::Rrf);
+
+        assert_eq!(results[0].0, "ml_engineer");
+        let ids: Vec<_> = results.iter().map(|(id, _)| id.as_str()).collect();
+        assert!(ids.contains(&"data_scientist"));
+    }
+
+    #[test]
+    fn test_weighted_sum_alpha_one_matches_bm25_only_ranking() {
+        let index = small_corpus();
+        let bm25_only = index.search(
+            "machine learning python",
+            &[0.0, 0.0, 0.0],
+            3,
+            FusionMode::WeightedSum { alpha: 1.0 },
+        );
+        let bm25_ranked = index.bm25.search("machine learning python", 3);
+
+        // With alpha = 1 each BM25 hit scores exactly its min-max normalized
+        // BM25 score; documents found only by the vector leg (here "designer")
+        // are still listed, with a fused score of 0.
+        let fused: HashMap<_, _> = bm25_only.iter().cloned().collect();
+        for (id, expected) in min_max_normalize(&bm25_ranked) {
+            assert!((fused[&id] - expected).abs() < 1e-6);
+        }
+        assert_eq!(bm25_only[0].0, bm25_ranked[0].0);
+    }
+
+    #[test]
+    fn test_weighted_sum_alpha_zero_matches_vector_only_ranking() {
+        let index = small_corpus();
+        let vector_only = index.search(
+            "irrelevant keywords",
+            &[1.0, 0.0, 0.0],
+            3,
+            FusionMode::WeightedSum { alpha: 0.0 },
+        );
+
+        assert_eq!(vector_only[0].0, "ml_engineer");
+        assert_eq!(vector_only[1].0, "data_scientist");
+    }
+
+    #[test]
+    fn test_cosine_similarity_identical_vectors_is_one() {
+        assert!((cosine_similarity(&[1.0, 2.0, 3.0], &[1.0, 2.0, 3.0]) - 1.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_cosine_similarity_orthogonal_vectors_is_zero() {
+        assert!(cosine_similarity(&[1.0, 0.0], &[0.0, 1.0]).abs() < 0.001);
+    }
+}
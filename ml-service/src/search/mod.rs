@@ -1,10 +1,14 @@
 mod bm25;
 mod hybrid;
+mod hybrid_index;
 mod qdrant;
+mod query;
 
 pub use bm25::BM25Index;
-pub use hybrid::{HybridSearch, SearchMode};
+pub use hybrid::{DistributionShift, FederatedQuery, HybridSearch, SearchMode};
+pub use hybrid_index::{FusionMode, HybridIndex};
 pub use qdrant::QdrantClient;
+pub use query::QueryNode;
 
 use std::collections::HashMap;
 
@@ -15,6 +19,9 @@ pub struct SearchResult {
     pub score: f32,
     pub metadata: HashMap<String, String>,
     pub source: SearchSource,
+    /// Per-source breakdown of how a fused score was produced. `None` for
+    /// single-source searches (`Vector`/`BM25`) where there's nothing to break down.
+    pub score_details: Option<ScoreDetails>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -33,3 +40,47 @@ impl std::fmt::Display for SearchSource {
         }
     }
 }
+
+/// Explains how a fused `SearchResult`'s score was produced, so callers like
+/// the `reranker` or gRPC clients can reason about why a document placed
+/// where it did instead of seeing only an opaque `f32`.
+#[derive(Debug, Clone, Default)]
+pub struct ScoreDetails {
+    /// Score from the vector search, if the document appeared there: the cosine
+    /// similarity after any `DistributionShift` the hybrid path applied to it.
+    pub vector_score: Option<f32>,
+    /// 0-based rank in the vector result list, if the document appeared there.
+    pub vector_rank: Option<usize>,
+    /// Raw BM25 score, if the document appeared in the keyword result list.
+    pub bm25_score: Option<f32>,
+    /// 0-based rank in the BM25 result list, if the document appeared there.
+    pub bm25_rank: Option<usize>,
+    pub fusion_method: FusionMethod,
+    /// The weight given to the vector leg when fusing (`vector_weight` for RRF,
+    /// `semantic_ratio` for linear fusion). `None` for single-source searches.
+    pub fusion_weight: Option<f32>,
+    /// The final fused score actually assigned to the result.
+    pub fused_score: f32,
+    /// Raw cross-encoder logit from the reranker, if reranking ran on this result.
+    pub rerank_score: Option<f32>,
+    /// This result's rank before reranking, if reranking ran.
+    pub rerank_original_rank: Option<i32>,
+    /// This result's rank after reranking, if reranking ran.
+    pub rerank_new_rank: Option<i32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FusionMethod {
+    #[default]
+    Rrf,
+    Linear,
+}
+
+impl std::fmt::Display for FusionMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FusionMethod::Rrf => write!(f, "rrf"),
+            FusionMethod::Linear => write!(f, "linear"),
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use anyhow::{Context, Result};
 use qdrant_client::qdrant::{
-    vectors_config::Config, CreateCollection, Distance, PointStruct, SearchPoints, VectorParams,
-    VectorsConfig, WithPayloadSelector, WithVectorsSelector,
+    vectors_config::Config, CreateCollection, Distance, NamedVectors, PointStruct, SearchPoints,
+    VectorParams, VectorParamsMap, Vectors, VectorsConfig, WithPayloadSelector,
+    WithVectorsSelector,
 };
 use qdrant_client::Qdrant;
 use std::collections::HashMap;
@@ -12,6 +13,10 @@ use crate::config::QdrantConfig;
 
 use super::SearchResult;
 
+/// Vector name used when a collection only ever holds a single embedding per
+/// document, so existing single-embedder callers don't need to name anything.
+pub const DEFAULT_VECTOR_NAME: &str = "default";
+
 /// Qdrant vector database client
 pub struct QdrantClient {
     client: Qdrant,
@@ -38,8 +43,24 @@ impl QdrantClient {
         format!("{}_{}", self.collection_prefix, name)
     }
 
-    /// Ensure a collection exists with the given dimensions
+    /// Ensure a collection exists with a single vector, named `DEFAULT_VECTOR_NAME`,
+    /// of the given dimensions.
     pub async fn ensure_collection(&self, name: &str, dimensions: u64) -> Result<()> {
+        let mut vectors = HashMap::new();
+        vectors.insert(DEFAULT_VECTOR_NAME.to_string(), dimensions);
+        self.ensure_collection_with_vectors(name, &vectors).await
+    }
+
+    /// Ensure a collection exists with one named vector per entry in `vectors`
+    /// (vector name -> dimensions). This lets a single collection hold several
+    /// embeddings per document - e.g. a fast small model alongside a
+    /// higher-quality large one, or separate title/body vectors - so embedders
+    /// can be swapped or A/B-tested without reindexing into a new collection.
+    pub async fn ensure_collection_with_vectors(
+        &self,
+        name: &str,
+        vectors: &HashMap<String, u64>,
+    ) -> Result<()> {
         let collection_name = self.collection_name(name);
 
         // Check if collection exists
@@ -50,17 +71,30 @@ impl QdrantClient {
             .any(|c| c.name == collection_name);
 
         if !exists {
-            info!("Creating collection: {} (dim={})", collection_name, dimensions);
+            info!(
+                "Creating collection: {} (vectors={:?})",
+                collection_name, vectors
+            );
+
+            let map = vectors
+                .iter()
+                .map(|(name, size)| {
+                    (
+                        name.clone(),
+                        VectorParams {
+                            size: *size,
+                            distance: Distance::Cosine.into(),
+                            ..Default::default()
+                        },
+                    )
+                })
+                .collect();
 
             self.client
                 .create_collection(CreateCollection {
                     collection_name: collection_name.clone(),
                     vectors_config: Some(VectorsConfig {
-                        config: Some(Config::Params(VectorParams {
-                            size: dimensions,
-                            distance: Distance::Cosine.into(),
-                            ..Default::default()
-                        })),
+                        config: Some(Config::ParamsMap(VectorParamsMap { map })),
                     }),
                     ..Default::default()
                 })
@@ -73,11 +107,32 @@ impl QdrantClient {
         Ok(())
     }
 
-    /// Index documents with embeddings
+    /// Index documents, each carrying a single unnamed embedding, into `DEFAULT_VECTOR_NAME`.
     pub async fn index_documents(
         &self,
         collection: &str,
         documents: Vec<(String, Vec<f32>, HashMap<String, String>)>,
+    ) -> Result<usize> {
+        self.index_documents_with_vectors(
+            collection,
+            documents
+                .into_iter()
+                .map(|(id, embedding, metadata)| {
+                    let mut vectors = HashMap::new();
+                    vectors.insert(DEFAULT_VECTOR_NAME.to_string(), embedding);
+                    (id, vectors, metadata)
+                })
+                .collect(),
+        )
+        .await
+    }
+
+    /// Index documents that each carry a named-vector map (vector name -> embedding),
+    /// so a point can hold several embeddings from different embedders at once.
+    pub async fn index_documents_with_vectors(
+        &self,
+        collection: &str,
+        documents: Vec<(String, HashMap<String, Vec<f32>>, HashMap<String, String>)>,
     ) -> Result<usize> {
         if documents.is_empty() {
             return Ok(0);
@@ -88,7 +143,7 @@ impl QdrantClient {
 
         let points: Vec<PointStruct> = documents
             .into_iter()
-            .map(|(id, embedding, metadata)| {
+            .map(|(id, embeddings, metadata)| {
                 // Convert metadata to Qdrant payload
                 let payload: HashMap<String, qdrant_client::qdrant::Value> = metadata
                     .into_iter()
@@ -99,6 +154,12 @@ impl QdrantClient {
                     })
                     .collect();
 
+                let mut named_vectors = NamedVectors::default();
+                for (name, vector) in embeddings {
+                    named_vectors = named_vectors.add_vector(name, vector);
+                }
+                let vectors: Vectors = named_vectors.into();
+
                 PointStruct::new(
                     // Use UUID if id is not valid, otherwise use the id as the point ID
                     id.parse::<u64>().unwrap_or_else(|_| {
@@ -106,7 +167,7 @@ impl QdrantClient {
                         let uuid = Uuid::new_v5(&Uuid::NAMESPACE_OID, id.as_bytes());
                         uuid.as_u128() as u64
                     }),
-                    embedding,
+                    vectors,
                     payload,
                 )
             })
@@ -123,16 +184,33 @@ impl QdrantClient {
         Ok(count)
     }
 
-    /// Search for similar vectors
+    /// Search the `DEFAULT_VECTOR_NAME` vector for similar vectors.
     pub async fn search(
         &self,
         collection: &str,
         query_vector: Vec<f32>,
         top_k: u64,
         filters: Option<HashMap<String, String>>,
+    ) -> Result<Vec<SearchResult>> {
+        self.search_named(collection, DEFAULT_VECTOR_NAME, query_vector, top_k, filters)
+            .await
+    }
+
+    /// Search a named vector for similar vectors, so callers can target a
+    /// specific embedder's vector on a collection that holds several.
+    pub async fn search_named(
+        &self,
+        collection: &str,
+        vector_name: &str,
+        query_vector: Vec<f32>,
+        top_k: u64,
+        filters: Option<HashMap<String, String>>,
     ) -> Result<Vec<SearchResult>> {
         let collection_name = self.collection_name(collection);
-        debug!("Searching {} for {} results", collection_name, top_k);
+        debug!(
+            "Searching {} (vector={}) for {} results",
+            collection_name, vector_name, top_k
+        );
 
         // Build filter if provided
         let filter = filters.map(|f| {
@@ -162,6 +240,7 @@ impl QdrantClient {
             .search_points(SearchPoints {
                 collection_name,
                 vector: query_vector,
+                vector_name: Some(vector_name.to_string()),
                 limit: top_k,
                 filter,
                 with_payload: Some(WithPayloadSelector {
@@ -204,6 +283,7 @@ impl QdrantClient {
                     score: point.score,
                     metadata,
                     source: super::SearchSource::Vector,
+                    score_details: None,
                 }
             })
             .collect();
@@ -0,0 +1,124 @@
+/// A single clause of a parsed BM25 query.
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryNode {
+    /// A bare term: contributes to the score but isn't required to match.
+    Should(String),
+    /// A `+term`-prefixed term: documents missing it are filtered out before scoring.
+    Must(String),
+    /// A `-term`-prefixed term: documents containing it are filtered out.
+    MustNot(String),
+    /// A `"quoted phrase"`: documents must contain these terms as an adjacent
+    /// run in that order, verified against the document's token sequence.
+    Phrase(Vec<String>),
+}
+
+/// Parse a query string into a small AST of `QueryNode`s: quoted text becomes
+/// a `Phrase`, a `+`-prefixed term becomes `Must`, a `-`-prefixed term becomes
+/// `MustNot`, and anything else becomes `Should`. Terms are lowercased and
+/// stripped of non-alphanumeric characters the same way documents are
+/// tokenized, so they compare equal to posting-list entries.
+pub fn parse_query(query: &str) -> Vec<QueryNode> {
+    let mut nodes = Vec::new();
+    let chars: Vec<char> = query.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i].is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        if chars[i] == '"' {
+            let start = i + 1;
+            let mut end = start;
+            while end < chars.len() && chars[end] != '"' {
+                end += 1;
+            }
+            let phrase: String = chars[start..end].iter().collect();
+            let terms = tokenize_term(&phrase);
+            if !terms.is_empty() {
+                nodes.push(QueryNode::Phrase(terms));
+            }
+            // Skip the closing quote too, if present.
+            i = if end < chars.len() { end + 1 } else { end };
+            continue;
+        }
+
+        let start = i;
+        while i < chars.len() && !chars[i].is_whitespace() {
+            i += 1;
+        }
+        let word: String = chars[start..i].iter().collect();
+
+        if let Some(rest) = word.strip_prefix('+') {
+            let terms = tokenize_term(rest);
+            if let Some(term) = terms.into_iter().next() {
+                nodes.push(QueryNode::Must(term));
+            }
+        } else if let Some(rest) = word.strip_prefix('-') {
+            let terms = tokenize_term(rest);
+            if let Some(term) = terms.into_iter().next() {
+                nodes.push(QueryNode::MustNot(term));
+            }
+        } else {
+            let terms = tokenize_term(&word);
+            if let Some(term) = terms.into_iter().next() {
+                nodes.push(QueryNode::Should(term));
+            }
+        }
+    }
+
+    nodes
+}
+
+/// Tokenize a single query fragment the same way `BM25Index::tokenize` does,
+/// so the resulting terms line up with posting-list keys and stored tokens.
+fn tokenize_term(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty() && s.len() > 1)
+        .map(|s| s.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_plain_terms_are_should() {
+        let nodes = parse_query("machine learning");
+        assert_eq!(
+            nodes,
+            vec![
+                QueryNode::Should("machine".to_string()),
+                QueryNode::Should("learning".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_quoted_phrase() {
+        let nodes = parse_query("\"machine learning\" engineer");
+        assert_eq!(
+            nodes,
+            vec![
+                QueryNode::Phrase(vec!["machine".to_string(), "learning".to_string()]),
+                QueryNode::Should("engineer".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_must_and_must_not() {
+        let nodes = parse_query("+python -java django");
+        assert_eq!(
+            nodes,
+            vec![
+                QueryNode::Must("python".to_string()),
+                QueryNode::MustNot("java".to_string()),
+                QueryNode::Should("django".to_string()),
+            ]
+        );
+    }
+}